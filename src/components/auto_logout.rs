@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use crate::utils::session::{now_secs, use_session};
+
+// setTimeout overflows past ~24.8 days; a token that long-lived gets rescheduled on next load
+const MAX_TIMEOUT_SECS: i64 = i32::MAX as i64 / 1000;
+
+/// When the access token expires, refreshes it if we can; otherwise signs the
+/// station out. Protected pages react to that and send the user to `/signin`.
+#[component]
+pub fn AutoLogout() -> impl IntoView {
+    let session = use_session();
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+
+    Effect::new(move |_| {
+        if let Some(handle) = timer.get_value() {
+            handle.clear();
+        }
+        let Some(current) = session.get() else {
+            timer.set_value(None);
+            return;
+        };
+        // Already expired with a refresh token: the next API call will refresh it
+        if current.is_expired() && current.refresh_token.is_some() {
+            timer.set_value(None);
+            return;
+        }
+
+        let delay = current.expires_in_secs(now_secs()).clamp(0, MAX_TIMEOUT_SECS) as u64;
+        let handle = set_timeout_with_handle(
+            move || {
+                leptos::task::spawn_local(async move {
+                    // A successful refresh updates the session, which reschedules this timer
+                    if current.refresh_token.is_some() && session.refresh().await.is_ok() {
+                        return;
+                    }
+                    session.sign_out();
+                });
+            },
+            Duration::from_secs(delay),
+        )
+        .ok();
+        timer.set_value(handle);
+    });
+
+    on_cleanup(move || {
+        if let Some(handle) = timer.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+}
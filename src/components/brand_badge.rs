@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+use crate::pages::fetch_nearest_stations_dto::Brand;
+use crate::utils::app_config::use_app_config;
+
+/// The marketer's logo when the deployment ships them, otherwise (or if the
+/// file fails to load) a round badge in their colours
+#[component]
+pub fn BrandBadge(brand: Brand) -> impl IntoView {
+    let i18n = use_i18n();
+    let logo = brand.logo_path().filter(|_| use_app_config().is_enabled("brand_logos"));
+    let logo_failed = RwSignal::new(false);
+
+    view! {
+        {move || match logo.clone().filter(|_| !logo_failed.get()) {
+            Some(src) => view! {
+                <img
+                    class="brand-logo"
+                    src=src
+                    alt=move || i18n.t(brand.label())
+                    title=move || i18n.t(brand.label())
+                    loading="lazy"
+                    on:error=move |_| logo_failed.set(true)
+                />
+            }.into_any(),
+            None => view! {
+                <span
+                    class=format!("brand-badge brand-{}", brand.code())
+                    title=move || i18n.t(brand.label())
+                >
+                    {brand.badge()}
+                </span>
+            }.into_any(),
+        }}
+    }
+}
@@ -0,0 +1,34 @@
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+use crate::utils::form::Field;
+
+/// An `<input>` bound to a form field. Extra attributes (`attr:style`, ...)
+/// pass straight through to the element.
+#[component]
+pub fn TextInput<T: 'static>(
+    field: Field<T>,
+    #[prop(into, default = Signal::stored("text"))] kind: Signal<&'static str>,
+    #[prop(optional)] autocomplete: Option<&'static str>,
+    #[prop(optional)] placeholder: Option<&'static str>,
+) -> impl IntoView {
+    view! {
+        <input
+            type=move || kind.get()
+            name=field.name()
+            autocomplete=autocomplete
+            placeholder=placeholder
+            prop:value=move || field.value()
+            class:error=move || field.error().is_some()
+            on:input=move |ev| field.set(event_target_value(&ev))
+            on:blur=move |_| field.touch()
+        />
+    }
+}
+
+/// The field's error, once it has been touched
+#[component]
+pub fn FieldError<T: 'static>(field: Field<T>) -> impl IntoView {
+    let i18n = use_i18n();
+    move || field.error().map(|msg| view! { <small class="error-message">{i18n.t(msg)}</small> })
+}
@@ -0,0 +1,99 @@
+use leptos::{logging, prelude::*, task::spawn_local};
+use web_sys::{HtmlImageElement, HtmlInputElement};
+
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::image_resize::{ImageKind, data_url_bytes, load_image, resize};
+
+/// File input with a preview of the image as it will be uploaded: cropped to
+/// shape, scaled down and compressed. `value` holds the result as a JPEG
+/// `data:` URL; `current` is what's already on the server, shown until
+/// something new is picked.
+#[component]
+pub fn ImagePicker(
+    kind: ImageKind,
+    value: RwSignal<Option<String>>,
+    current: Option<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    // The decoded original, kept so the crop can be moved without re-reading the file
+    let source = StoredValue::new_local(None::<HtmlImageElement>);
+    let has_source = RwSignal::new(false);
+    let focus = RwSignal::new(0.5);
+    let error = RwSignal::new(None::<Msg>);
+
+    let render = move || {
+        let result = source.with_value(|img| img.as_ref().map(|img| resize(img, kind, focus.get_untracked())));
+        match result {
+            Some(Ok(data_url)) => value.set(Some(data_url)),
+            Some(Err(e)) => {
+                logging::warn!("Resizing the {} failed: {}", kind.path(), e);
+                error.set(Some(Msg::ImageUnreadable));
+            }
+            None => {}
+        }
+    };
+
+    let on_pick = move |ev: leptos::ev::Event| {
+        let input: HtmlInputElement = event_target(&ev);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+        error.set(None);
+        spawn_local(async move {
+            match load_image(&file).await {
+                Ok(img) => {
+                    source.set_value(Some(img));
+                    has_source.set(true);
+                    focus.set(0.5);
+                    render();
+                }
+                Err(e) => {
+                    logging::warn!("Loading the {} failed: {}", kind.path(), e);
+                    error.set(Some(Msg::ImageUnreadable));
+                }
+            }
+        });
+    };
+
+    let clear = move |_| {
+        source.set_value(None);
+        has_source.set(false);
+        value.set(None);
+    };
+
+    view! {
+        <div class="image-picker">
+            {move || value.get().or_else(|| current.clone()).map(|src| view! {
+                <img class=format!("crop-preview {}", kind.path()) src=src alt="" />
+            })}
+            <Show when=move || has_source.get()>
+                <label class="crop-focus">
+                    {move || i18n.t(Msg::CropPosition)}
+                    <input
+                        type="range"
+                        min="0"
+                        max="100"
+                        prop:value=move || (focus.get() * 100.0).round().to_string()
+                        on:change=move |ev| {
+                            focus.set(event_target_value(&ev).parse::<f64>().unwrap_or(50.0) / 100.0);
+                            render();
+                        }
+                    />
+                </label>
+                <small class="image-size">
+                    {move || value.get().map(|url| {
+                        let kb = data_url_bytes(&url).div_ceil(1024);
+                        i18n.t(Msg::ImageSizeKb).replace("{kb}", &kb.to_string())
+                    })}
+                </small>
+            </Show>
+            <div class="image-picker-actions">
+                <input type="file" accept="image/*" on:change=on_pick />
+                <Show when=move || value.with(Option::is_some)>
+                    <button type="button" class="link-button" on:click=clear>
+                        {move || i18n.t(Msg::Remove)}
+                    </button>
+                </Show>
+            </div>
+            {move || error.get().map(|msg| view! { <small class="error-message">{i18n.t(msg)}</small> })}
+        </div>
+    }
+}
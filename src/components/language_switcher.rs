@@ -0,0 +1,29 @@
+use leptos::prelude::*;
+
+use crate::i18n::{Locale, Msg, use_i18n};
+
+/// Dropdown of the supported languages; the choice is remembered in local storage
+#[component]
+pub fn LanguageSwitcher() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <label class="language-switcher">
+            <span>{move || i18n.t(Msg::Language)}</span>
+            <select
+                prop:value=move || i18n.locale().code()
+                on:change=move |ev| {
+                    if let Some(locale) = Locale::from_code(&event_target_value(&ev)) {
+                        i18n.set_locale(locale);
+                    }
+                }
+            >
+                {Locale::ALL.into_iter().map(|locale| view! {
+                    <option value=locale.code() selected=move || i18n.locale() == locale>
+                        {locale.native_name()}
+                    </option>
+                }).collect_view()}
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::live_prices::LivePrices;
+
+/// Small "live" badge showing whether price pushes are currently coming through
+#[component]
+pub fn LiveIndicator(live: LivePrices) -> impl IntoView {
+    let i18n = use_i18n();
+
+    live.enabled.then(|| view! {
+        <span class=move || if live.is_live.get() { "live-indicator is-live" } else { "live-indicator" }>
+            <span class="live-dot"></span>
+            {move || if live.is_live.get() { i18n.t(Msg::Live) } else { i18n.t(Msg::Reconnecting) }}
+        </span>
+    })
+}
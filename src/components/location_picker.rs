@@ -0,0 +1,108 @@
+use leptos::{html, prelude::*};
+
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::app_config::use_app_config;
+use crate::utils::get_gps_location::GpsFix;
+use crate::utils::web_mercator::{TILE_SIZE, from_world_px, metres_per_pixel, tile_url, to_world_px};
+
+// The map is a fixed square, so the tile grid only has to be worked out once
+const VIEW_PX: f64 = 300.0;
+const MAX_ZOOM: u8 = 18;
+const MIN_ZOOM: u8 = 13;
+
+/// Closest zoom at which the whole accuracy circle still fits on the map
+fn zoom_for(fix: &GpsFix) -> u8 {
+    let mut zoom = MAX_ZOOM;
+    while zoom > MIN_ZOOM && fix.accuracy_m / metres_per_pixel(fix.latitude, zoom) > VIEW_PX * 0.4 {
+        zoom -= 1;
+    }
+    zoom
+}
+
+/// Small tile map centred on a GPS fix, with the fix's accuracy circle and a
+/// pin the owner can drag (or tap) onto the exact spot. `pin` holds the chosen
+/// `(lat, lon)`; it starts at the fix and is kept inside the visible map.
+#[component]
+pub fn LocationPicker(fix: GpsFix, pin: RwSignal<(f64, f64)>) -> impl IntoView {
+    let i18n = use_i18n();
+    let tile_template = use_app_config().map_tile_url;
+    let map_ref = NodeRef::<html::Div>::new();
+    let dragging = RwSignal::new(false);
+
+    let zoom = zoom_for(&fix);
+    let (cx, cy) = to_world_px(fix.latitude, fix.longitude, zoom);
+    // World pixel under the map's top-left corner
+    let (ox, oy) = (cx - VIEW_PX / 2.0, cy - VIEW_PX / 2.0);
+    let radius = fix.accuracy_m / metres_per_pixel(fix.latitude, zoom);
+
+    let first = |o: f64| (o / TILE_SIZE).floor() as i64;
+    let last = |o: f64| ((o + VIEW_PX) / TILE_SIZE).floor() as i64;
+    let tiles = (first(oy)..=last(oy))
+        .flat_map(|ty| (first(ox)..=last(ox)).map(move |tx| (tx, ty)))
+        .map(|(tx, ty)| {
+            let style = format!(
+                "left: {}px; top: {}px;",
+                tx as f64 * TILE_SIZE - ox,
+                ty as f64 * TILE_SIZE - oy
+            );
+            let src = tile_url(&tile_template, zoom, tx, ty);
+            view! { <img class="map-tile" src=src style=style alt="" draggable="false" /> }
+        })
+        .collect_view();
+
+    let move_pin = move |client_x: i32, client_y: i32| {
+        let Some(map) = map_ref.get_untracked() else { return };
+        let rect = map.get_bounding_client_rect();
+        let x = (f64::from(client_x) - rect.left()).clamp(0.0, VIEW_PX);
+        let y = (f64::from(client_y) - rect.top()).clamp(0.0, VIEW_PX);
+        pin.set(from_world_px(ox + x, oy + y, zoom));
+    };
+
+    let pin_style = move || {
+        let (lat, lon) = pin.get();
+        let (x, y) = to_world_px(lat, lon, zoom);
+        format!("left: {}px; top: {}px;", x - ox, y - oy)
+    };
+
+    view! {
+        <div class="location-picker">
+            <div
+                class="map-view"
+                class:dragging=move || dragging.get()
+                node_ref=map_ref
+                style=format!("width: {VIEW_PX}px; height: {VIEW_PX}px;")
+                on:pointerdown=move |ev| {
+                    dragging.set(true);
+                    move_pin(ev.client_x(), ev.client_y());
+                }
+                on:pointermove=move |ev| {
+                    if dragging.get_untracked() {
+                        move_pin(ev.client_x(), ev.client_y());
+                    }
+                }
+                on:pointerup=move |_| dragging.set(false)
+                on:pointerleave=move |_| dragging.set(false)
+            >
+                {tiles}
+                <div
+                    class="accuracy-circle"
+                    style=format!(
+                        "left: {c}px; top: {c}px; width: {d}px; height: {d}px;",
+                        c = VIEW_PX / 2.0,
+                        d = radius * 2.0
+                    )
+                ></div>
+                <div class="map-pin" style=pin_style></div>
+                <small class="map-attribution">"© OpenStreetMap contributors"</small>
+            </div>
+            <small class="location-hint">{move || i18n.t(Msg::LocationHint)}</small>
+            <button
+                type="button"
+                class="link-button"
+                on:click=move |_| pin.set((fix.latitude, fix.longitude))
+            >
+                {move || i18n.t(Msg::ResetPin)}
+            </button>
+        </div>
+    }
+}
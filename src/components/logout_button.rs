@@ -0,0 +1,36 @@
+use leptos::{logging, prelude::*};
+use leptos_router::hooks::use_navigate;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::stations::dto::logout_station;
+use crate::utils::session::use_session;
+
+/// Ends the session on the server, clears it from this browser and goes to `/signin`
+#[component]
+pub fn LogoutButton() -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let navigate = use_navigate();
+
+    let logout_action = Action::new_local(move |_: &()| {
+        let navigate = navigate.clone();
+        async move {
+            // Best effort: even if the API can't be reached the token is gone from this browser
+            if let Err(e) = logout_station(session).await {
+                logging::warn!("Server logout failed: {}", e);
+            }
+            session.sign_out();
+            navigate("/signin", Default::default());
+        }
+    });
+
+    view! {
+        <button
+            class="logout-button"
+            disabled=move || logout_action.pending().get()
+            on:click=move |_| { logout_action.dispatch(()); }
+        >
+            {move || if logout_action.pending().get() { i18n.t(Msg::LoggingOut) } else { i18n.t(Msg::Logout) }}
+        </button>
+    }
+}
@@ -1 +1,12 @@
+#[allow(dead_code)]
 pub mod counter_btn;
+pub mod language_switcher;
+pub mod live_indicator;
+pub mod auto_logout;
+pub mod logout_button;
+pub mod protected;
+pub mod password_strength;
+pub mod form_field;
+pub mod location_picker;
+pub mod image_picker;
+pub mod brand_badge;
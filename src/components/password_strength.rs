@@ -0,0 +1,26 @@
+use leptos::prelude::*;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::validation::password_strength;
+
+/// Four-segment bar under a new-password field, updated as the user types
+#[component]
+pub fn PasswordStrengthMeter(#[prop(into)] password: Signal<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let strength = Memo::new(move |_| password.with(|p| password_strength(p)));
+
+    view! {
+        <div class="password-strength">
+            {move || (!password.with(String::is_empty)).then(|| {
+                let level = strength.get();
+                view! {
+                    <div class=format!("strength-bar {}", level.class())>
+                        <span></span><span></span><span></span><span></span>
+                    </div>
+                    <small class="strength-label">{i18n.t(level.msg())}</small>
+                }
+            })}
+            <small class="password-hint">{move || i18n.t(Msg::PasswordHint)}</small>
+        </div>
+    }
+}
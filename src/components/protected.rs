@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use leptos_router::components::Redirect;
+use leptos_router::hooks::use_location;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::protect_route::signin_redirect;
+use crate::utils::session::use_session;
+
+/// What `Protected` shows, kept apart from the session itself so a token
+/// refresh (a new `Session` with the same access) doesn't rebuild the page
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Access {
+    SignedOut,
+    NotAllowed,
+    Allowed,
+}
+
+/// Renders `children` only for a signed-in session, optionally with one of `roles`.
+/// Anyone signed out is sent to `/signin?next=<this page>`; a signed-in user
+/// without the right role gets a "not allowed" message instead of a redirect loop.
+#[component]
+pub fn Protected(
+    #[prop(optional)] roles: &'static [&'static str],
+    children: ChildrenFn,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let location = use_location();
+    let children = StoredValue::new(children);
+
+    let access = Memo::new(move |_| {
+        session.with(|s| match s.as_ref().filter(|s| s.is_usable()) {
+            None => Access::SignedOut,
+            Some(s) if !roles.is_empty() && !s.has_any_role(roles) => Access::NotAllowed,
+            Some(_) => Access::Allowed,
+        })
+    });
+
+    move || match access.get() {
+        Access::SignedOut => {
+            let return_to = format!("{}{}", location.pathname.get_untracked(), location.search.get_untracked());
+            view! {
+                <Redirect path=signin_redirect(&return_to) />
+                <p>{i18n.t(Msg::Redirecting)}</p>
+            }.into_any()
+        }
+        Access::NotAllowed => {
+            view! { <p class="error-message">{i18n.t(Msg::NotAllowed)}</p> }.into_any()
+        }
+        Access::Allowed => children.read_value()().into_any(),
+    }
+}
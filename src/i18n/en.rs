@@ -0,0 +1,225 @@
+use super::Msg;
+
+/// The source catalog: every message must have an English string.
+pub fn message(msg: Msg) -> &'static str {
+    match msg {
+        Msg::Language => "Language",
+        Msg::NeedHelp => "Need help? ",
+        Msg::Redirecting => "Redirecting...",
+        Msg::FindFuel => "Find Fuel",
+        Msg::Finding => "Finding...",
+        Msg::NoStationsFound => "No stations found in your area.",
+        Msg::SomethingWentWrong => "Oops! something went wrong ",
+        Msg::StationsWillAppear => "Stations will appear here (service currently available only in {regions})",
+        Msg::OutsideServiceArea => "Seems like you are outside the {regions} service area.",
+        Msg::NoServiceArea => "The service is not available in any region right now.",
+        Msg::PriceLabel => "Price: ",
+        Msg::AddressLabel => "Address: ",
+        Msg::DirectionsLabel => "Directions: ",
+        Msg::OpenGoogleMaps => "Open Google Maps",
+        Msg::DistanceLabel => "Distance: ",
+        Msg::NotApplicable => "N/A",
+        Msg::Name => "Name",
+        Msg::Address => "Address",
+        Msg::Email => "Email",
+        Msg::Phone => "Phone",
+        Msg::Password => "Password",
+        Msg::RegistrationCode => "Registration Code",
+        Msg::ShowPassword => "show",
+        Msg::HidePassword => "hide",
+        Msg::Login => "Login",
+        Msg::Register => "Register",
+        Msg::Registering => "Registering...",
+        Msg::NoAccount => "Do not have an account? ",
+        Msg::AlreadyRegistered => "Already registered? ",
+        Msg::SignupNote => "Note: Please register from your station's forecourt. We use your GPS location, and you can fine-tune the pin on the map below.",
+        Msg::RegisterStationTitle => "Register Filling Station",
+        Msg::ErrorOccurred => "Oops! an error occurred: ",
+        Msg::NameRequired => "Name is required",
+        Msg::AddressRequired => "Address is required",
+        Msg::EmailRequired => "Email is required",
+        Msg::PasswordRequired => "Password is required",
+        Msg::InvalidPhone => "Invalid phone number",
+        Msg::EmailInvalid => "Enter a valid email address, like name@example.com",
+        Msg::PhoneRequired => "Phone number is required",
+        Msg::PhoneNotMobile => "Enter a Nigerian mobile number, like 0803 123 4567",
+        Msg::PasswordTooShort => "Password must be at least 8 characters",
+        Msg::PasswordTooLong => "Password is too long",
+        Msg::PasswordTooWeak => "This password is too easy to guess",
+        Msg::PasswordWeak => "Weak",
+        Msg::PasswordFair => "Fair",
+        Msg::PasswordGood => "Good",
+        Msg::PasswordStrong => "Strong",
+        Msg::PasswordHint => "Use 8 or more characters with a mix of letters, numbers and symbols.",
+        Msg::CodeRequired => "Code is required",
+        Msg::GpsFailed => "Could not determine GPS location.",
+        Msg::LoadingDashboard => "Loading dashboard data...",
+        Msg::UnauthorizedRedirecting => "Unauthorized - Redirecting...",
+        Msg::PricePerLitre => " - Price/Litre",
+        Msg::Update => "Update",
+        Msg::Save => "Save",
+        Msg::Cancel => "Cancel",
+        Msg::Available => "Available",
+        Msg::NotAvailable => "Not Available",
+        Msg::UpdatePriceFirst => "Please update price first",
+        Msg::PriceRequired => "Enter a price",
+        Msg::PriceInvalid => "Price must be a number like 650 or 1,250.50",
+        Msg::PriceNegative => "Price cannot be negative",
+        Msg::PriceTooPrecise => "Price can only have up to 2 decimal places (kobo)",
+        Msg::PriceTooLarge => "That price is too high, please check for an extra zero",
+        Msg::Live => "Live",
+        Msg::Reconnecting => "Reconnecting...",
+        Msg::Logout => "Log out",
+        Msg::LoggingOut => "Logging out...",
+        Msg::ActiveSessions => "Active sessions",
+        Msg::LoadingSessions => "Loading sessions...",
+        Msg::NoActiveSessions => "No other devices are signed in.",
+        Msg::UnknownDevice => "Unknown device",
+        Msg::ThisDevice => "This device",
+        Msg::LastActive => "Last active ",
+        Msg::Revoke => "Sign out",
+        Msg::Revoking => "Signing out...",
+        Msg::ForgotPassword => "Forgot password?",
+        Msg::ForgotPasswordTitle => "Reset your password",
+        Msg::ForgotPasswordHint => "Enter the email your station is registered with and we'll send you a link to choose a new password.",
+        Msg::SendResetLink => "Send reset link",
+        Msg::Sending => "Sending...",
+        Msg::ResetLinkSent => "If that email is registered, a reset link is on its way. Check your inbox (and spam folder).",
+        Msg::BackToLogin => "Back to login",
+        Msg::RequestNewLink => "Request a new link",
+        Msg::ResetPasswordTitle => "Choose a new password",
+        Msg::NewPassword => "New password",
+        Msg::ConfirmPassword => "Confirm password",
+        Msg::PasswordsDoNotMatch => "Passwords do not match",
+        Msg::ResetPassword => "Reset password",
+        Msg::Saving => "Saving...",
+        Msg::PasswordResetDone => "Your password has been changed. You can now log in with it.",
+        Msg::ResetTokenMissing => "This reset link is invalid or incomplete. Please request a new one.",
+        Msg::CheckEmailTitle => "Check your email",
+        Msg::CheckEmailBody => "We sent a verification link to {email}. Open it to activate your station, then log in.",
+        Msg::ResendLink => "Resend link",
+        Msg::ResendIn => "Resend in {seconds}s",
+        Msg::LinkResent => "A new link is on its way.",
+        Msg::VerifyingEmail => "Verifying your email...",
+        Msg::EmailVerified => "Your email is verified. Thank you!",
+        Msg::VerificationLinkInvalid => "This verification link is incomplete. Please use the link from your email.",
+        Msg::GoToLogin => "Go to login",
+        Msg::GoToDashboard => "Go to dashboard",
+        Msg::NotAllowed => "You don't have access to this page.",
+        Msg::UnverifiedBanner => "Your email address hasn't been verified yet. Please click the link we emailed you.",
+        Msg::Admin => "Admin",
+        Msg::AdminStations => "Stations",
+        Msg::RegistrationActivity => "Registrations",
+        Msg::SearchStations => "Search by name, email or phone",
+        Msg::LoadingStations => "Loading stations...",
+        Msg::Suspend => "Suspend",
+        Msg::Reactivate => "Reactivate",
+        Msg::SignOutEverywhere => "Sign out everywhere",
+        Msg::Suspended => "Suspended",
+        Msg::Unverified => "Unverified",
+        Msg::RegisteredOn => "Registered ",
+        Msg::ConfirmAccountAction => "This will sign the station out. Continue?",
+        Msg::RegistrationsInDays => "{count} new stations in the last {days} days",
+        Msg::RecentRegistrations => "Newest stations",
+        Msg::Staff => "Staff accounts",
+        Msg::LoadingStaff => "Loading staff...",
+        Msg::NoStaff => "No staff yet. Invite your attendants so they don't need your password.",
+        Msg::InviteStaff => "Invite staff",
+        Msg::StaffInvited => "Invite sent. They'll get an email to set their password.",
+        Msg::InvitePending => "Invite pending",
+        Msg::CanUpdatePrices => "Update prices",
+        Msg::CanToggleAvailability => "Toggle availability",
+        Msg::ViewOnly => "View only",
+        Msg::Remove => "Remove",
+        Msg::Removing => "Removing...",
+        Msg::UpdatedBy => "Updated by {name}",
+        Msg::AcceptInviteTitle => "Set up your staff account",
+        Msg::AcceptInvite => "Create account",
+        Msg::InviteAccepted => "Your account is ready. Log in with your email and new password.",
+        Msg::InviteTokenMissing => "This invite link is incomplete. Please use the link from your email.",
+        Msg::TwoFactor => "Two-factor authentication",
+        Msg::TwoFactorOn => "On. Logging in needs a code from your authenticator app.",
+        Msg::TwoFactorOff => "Protect your prices from a stolen password: logging in will also need a code from an authenticator app on your phone.",
+        Msg::SetUpTwoFactor => "Set up two-factor",
+        Msg::ScanQrCode => "Scan this with Google Authenticator, Authy or any authenticator app, then enter the 6-digit code it shows.",
+        Msg::CantScan => "Can't scan? Enter this key:",
+        Msg::AuthCode => "Authentication code",
+        Msg::Verify => "Verify",
+        Msg::Verifying => "Verifying...",
+        Msg::RecoveryCodesHint => "If you lose your phone, each of these codes lets you log in once. Save them somewhere safe; they won't be shown again.",
+        Msg::DownloadCodes => "Download codes",
+        Msg::SavedCodes => "I've saved these codes",
+        Msg::EnterCodeToChange => "Enter a code from your app to make changes",
+        Msg::NewRecoveryCodes => "New recovery codes",
+        Msg::DisableTwoFactor => "Turn off",
+        Msg::TwoFactorPrompt => "Enter the 6-digit code from your authenticator app.",
+        Msg::RecoveryCodePrompt => "Enter one of the recovery codes you saved when you set up two-factor.",
+        Msg::RecoveryCode => "Recovery code",
+        Msg::UseRecoveryCode => "Use a recovery code instead",
+        Msg::UseAuthenticator => "Use my authenticator app",
+        Msg::OtpInvalid => "Enter the 6-digit code",
+        Msg::RecoveryCodeRequired => "Enter a recovery code",
+        Msg::InvalidCredentials => "Wrong email or password",
+        Msg::InvalidCode => "That code didn't work, please try again",
+        Msg::TooManyAttempts => "Too many login attempts. Please wait a moment before trying again.",
+        Msg::AccountLocked => "This account is locked for now after too many failed logins. Wait for the timer, or reset your password.",
+        Msg::AccountSuspended => "This station account has been suspended. Please contact support to restore access.",
+        Msg::TryAgainIn => "Try again in {time}",
+        Msg::LocationTitle => "Station location",
+        Msg::LocationHint => "Drag or tap to move the pin onto your forecourt.",
+        Msg::LocatingYou => "Finding your location...",
+        Msg::AccuracyWithin => "GPS accurate to about {meters} m",
+        Msg::LocationTooImprecise => "Your GPS reading is too rough. Turn on precise location or step outside, then try again.",
+        Msg::LocationRequired => "We need your location to register the station.",
+        Msg::RetryLocation => "Locate me again",
+        Msg::ResetPin => "Back to GPS position",
+        Msg::GpsPermissionDenied => "Location access is blocked. Allow it in your browser's site settings, then try again.",
+        Msg::GpsTimeout => "GPS took too long to respond. Step outside or near a window and try again.",
+        Msg::GpsUnsupported => "This browser can't share your location.",
+        Msg::LocationStale => "Your location reading is out of date. Locate again before continuing.",
+        Msg::StepBusiness => "Business",
+        Msg::StepContact => "Contact",
+        Msg::StepLocation => "Location",
+        Msg::StepReview => "Review",
+        Msg::Back => "Back",
+        Msg::Next => "Next",
+        Msg::Edit => "Edit",
+        Msg::ReviewHint => "Check your details, then register your station.",
+        Msg::DraftRestored => "We kept what you entered last time. Your password isn't saved, so you'll need to type it again.",
+        Msg::StartOver => "Start over",
+        Msg::StepCommodities => "Products",
+        Msg::CommoditiesHint => "Tick what you sell and enter today's price, per litre (LPG per kg). You can change prices any time from your dashboard.",
+        Msg::SelectCommodity => "Pick at least one product you sell.",
+        Msg::PriceZero => "Price must be more than ₦0",
+        Msg::CommodityPms => "PMS (petrol)",
+        Msg::CommodityAgo => "AGO (diesel)",
+        Msg::CommodityDpk => "DPK (kerosene)",
+        Msg::CommodityLpg => "LPG (cooking gas)",
+        Msg::Photos => "Photos",
+        Msg::PhotosHint => "Optional. A clear photo of your forecourt helps drivers recognise you.",
+        Msg::StationPhoto => "Station photo",
+        Msg::StationLogo => "Logo",
+        Msg::CropPosition => "Crop position",
+        Msg::ImageSizeKb => "About {kb} KB to upload",
+        Msg::ImageUnreadable => "We couldn't read that image. Try a JPEG or PNG.",
+        Msg::ImageTooLarge => "That image is too large to upload.",
+        Msg::SignupTooLarge => "Your photos are too large to send with the signup. Remove them and add them from your dashboard instead.",
+        Msg::Upload => "Upload",
+        Msg::Uploading => "Uploading...",
+        Msg::NoneAdded => "None",
+        Msg::Brand => "Brand",
+        Msg::AllBrands => "All brands",
+        Msg::BrandNnpc => "NNPC",
+        Msg::BrandTotalEnergies => "TotalEnergies",
+        Msg::BrandConoil => "Conoil",
+        Msg::BrandMrs => "MRS",
+        Msg::BrandIndependent => "Independent",
+        Msg::NoStationsForBrand => "None of the nearby stations are from that brand.",
+        Msg::ChooseBrand => "Choose the brand you sell for",
+        Msg::UpdateAllPrices => "Update all prices",
+        Msg::SaveAll => "Save all",
+        Msg::NothingChanged => "Nothing has changed yet.",
+        Msg::PriceColumn => "Price",
+        Msg::PricesNotAllowed => "You're not allowed to change some of these prices.",
+    }
+}
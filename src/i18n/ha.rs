@@ -0,0 +1,55 @@
+use super::Msg;
+
+/// Hausa.
+pub fn message(msg: Msg) -> Option<&'static str> {
+    let text = match msg {
+        Msg::Language => "Harshe",
+        Msg::NeedHelp => "Kuna bukatar taimako? ",
+        Msg::Redirecting => "Ana juyawa...",
+        Msg::FindFuel => "Nemo Mai",
+        Msg::Finding => "Ana nema...",
+        Msg::NoStationsFound => "Ba a sami gidan mai a yankinku ba.",
+        Msg::SomethingWentWrong => "Kash! Wani abu ya faru ba daidai ba ",
+        Msg::StationsWillAppear => "Gidajen mai za su bayyana a nan (sabis yana samuwa a {regions} kawai)",
+        Msg::PriceLabel => "Farashi: ",
+        Msg::AddressLabel => "Adireshi: ",
+        Msg::DirectionsLabel => "Hanya: ",
+        Msg::OpenGoogleMaps => "Bude Google Maps",
+        Msg::DistanceLabel => "Nisa: ",
+        Msg::NotApplicable => "Babu",
+        Msg::Name => "Suna",
+        Msg::Address => "Adireshi",
+        Msg::Email => "Imel",
+        Msg::Phone => "Waya",
+        Msg::Password => "Kalmar sirri",
+        Msg::RegistrationCode => "Lambar Rajista",
+        Msg::ShowPassword => "nuna",
+        Msg::HidePassword => "boye",
+        Msg::Login => "Shiga",
+        Msg::Register => "Yi rajista",
+        Msg::Registering => "Ana rajista...",
+        Msg::NoAccount => "Ba ku da asusu? ",
+        Msg::AlreadyRegistered => "Kun riga kun yi rajista? ",
+        Msg::SignupNote => "Lura: Da fatan za a yi rajista a ainihin wurin gidan mai naku, domin muna adana wurin GPS kai tsaye.",
+        Msg::RegisterStationTitle => "Yi Rajistar Gidan Mai",
+        Msg::ErrorOccurred => "Kash! An samu kuskure: ",
+        Msg::NameRequired => "Ana bukatar suna",
+        Msg::AddressRequired => "Ana bukatar adireshi",
+        Msg::EmailRequired => "Ana bukatar imel",
+        Msg::PasswordRequired => "Ana bukatar kalmar sirri",
+        Msg::InvalidPhone => "Lambar waya ba daidai ba ce",
+        Msg::CodeRequired => "Ana bukatar lamba",
+        Msg::GpsFailed => "Ba a iya gano wurin GPS ba.",
+        Msg::LoadingDashboard => "Ana loda bayanan dashboard...",
+        Msg::UnauthorizedRedirecting => "Ba a ba da izini ba - Ana juyawa...",
+        Msg::PricePerLitre => " - Farashi/Lita",
+        Msg::Update => "Sabunta",
+        Msg::Save => "Ajiye",
+        Msg::Cancel => "Soke",
+        Msg::Available => "Akwai",
+        Msg::NotAvailable => "Babu",
+        Msg::UpdatePriceFirst => "Da fatan za a sabunta farashi da farko",
+        _ => return None,
+    };
+    Some(text)
+}
@@ -0,0 +1,54 @@
+use super::Msg;
+
+/// Igbo.
+pub fn message(msg: Msg) -> Option<&'static str> {
+    let text = match msg {
+        Msg::Language => "Asụsụ",
+        Msg::NeedHelp => "Ị chọrọ enyemaka? ",
+        Msg::Redirecting => "Na-eduga gị...",
+        Msg::FindFuel => "Chọta Mmanụ",
+        Msg::Finding => "Na-achọ...",
+        Msg::NoStationsFound => "Ahụghị ọdụ mmanụ ọ bụla n'ebe ị nọ.",
+        Msg::SomethingWentWrong => "Ewoo! Ihe adabaghị ",
+        Msg::StationsWillAppear => "Ọdụ mmanụ ga-apụta ebe a (ọrụ a dị naanị na {regions} ugbu a)",
+        Msg::PriceLabel => "Ọnụahịa: ",
+        Msg::AddressLabel => "Adreesị: ",
+        Msg::DirectionsLabel => "Ụzọ: ",
+        Msg::OpenGoogleMaps => "Mepee Google Maps",
+        Msg::DistanceLabel => "Anya: ",
+        Msg::NotApplicable => "Enweghị",
+        Msg::Name => "Aha",
+        Msg::Address => "Adreesị",
+        Msg::Phone => "Ekwentị",
+        Msg::Password => "Okwuntughe",
+        Msg::RegistrationCode => "Koodu Ndebanye",
+        Msg::ShowPassword => "gosi",
+        Msg::HidePassword => "zoo",
+        Msg::Login => "Banye",
+        Msg::Register => "Debanye aha",
+        Msg::Registering => "Na-edebanye aha...",
+        Msg::NoAccount => "Ị nweghị akaụntụ? ",
+        Msg::AlreadyRegistered => "Ị debanyelarị aha? ",
+        Msg::SignupNote => "Rịba ama: Biko debanye aha n'ezie ebe ọdụ mmanụ gị dị, n'ihi na anyị na-echekwa ọnọdụ GPS na-akpaghị aka.",
+        Msg::RegisterStationTitle => "Debanye Ọdụ Mmanụ",
+        Msg::ErrorOccurred => "Ewoo! Njehie mere: ",
+        Msg::NameRequired => "Achọrọ aha",
+        Msg::AddressRequired => "Achọrọ adreesị",
+        Msg::EmailRequired => "Achọrọ email",
+        Msg::PasswordRequired => "Achọrọ okwuntughe",
+        Msg::InvalidPhone => "Nọmba ekwentị ezighi ezi",
+        Msg::CodeRequired => "Achọrọ koodu",
+        Msg::GpsFailed => "Enweghị ike ịchọpụta ọnọdụ GPS.",
+        Msg::LoadingDashboard => "Na-ebuga data dashboard...",
+        Msg::UnauthorizedRedirecting => "Enweghị ikike - Na-eduga gị...",
+        Msg::PricePerLitre => " - Ọnụahịa/Lita",
+        Msg::Update => "Melite",
+        Msg::Save => "Chekwaa",
+        Msg::Cancel => "Kagbuo",
+        Msg::Available => "Dị",
+        Msg::NotAvailable => "Adịghị",
+        Msg::UpdatePriceFirst => "Biko melite ọnụahịa mbụ",
+        _ => return None,
+    };
+    Some(text)
+}
@@ -0,0 +1,424 @@
+use leptos::prelude::*;
+
+use crate::utils::money::Money;
+
+// `en` is the source catalog and must cover every `Msg`. The others are
+// partial on purpose: they carry the strings people see most (search, prices,
+// sign in) and anything they leave out is shown in English. New strings land
+// in English first and get translated when a reviewer for that language is
+// available, so a missing entry is expected, not a bug.
+mod en;
+mod ha;
+mod ig;
+mod pcm;
+mod yo;
+
+const STORAGE_KEY: &str = "locale";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Ha,
+    Yo,
+    Ig,
+    Pcm,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 5] = [Locale::En, Locale::Ha, Locale::Yo, Locale::Ig, Locale::Pcm];
+
+    /// BCP 47 tag, also what we store and put on `<html lang>`
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Ha => "ha",
+            Locale::Yo => "yo",
+            Locale::Ig => "ig",
+            Locale::Pcm => "pcm",
+        }
+    }
+
+    /// The language's name in itself, for the switcher
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Ha => "Hausa",
+            Locale::Yo => "Yorùbá",
+            Locale::Ig => "Igbo",
+            Locale::Pcm => "Naijá",
+        }
+    }
+
+    /// Accepts "yo", "yo-NG", "YO_ng"...
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let lang = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        Locale::ALL.into_iter().find(|l| l.code() == lang)
+    }
+
+    /// Falls back to English for anything a catalog hasn't translated, see
+    /// the note on the catalog modules above
+    pub fn t(self, msg: Msg) -> &'static str {
+        let translated = match self {
+            Locale::En => None,
+            Locale::Ha => ha::message(msg),
+            Locale::Yo => yo::message(msg),
+            Locale::Ig => ig::message(msg),
+            Locale::Pcm => pcm::message(msg),
+        };
+        translated.unwrap_or_else(|| en::message(msg))
+    }
+
+    // (group, decimal). CLDR uses the same separators for all of these in Nigeria,
+    // but keep them per locale so a new language doesn't have to assume it.
+    fn separators(self) -> (char, char) {
+        match self {
+            Locale::En | Locale::Ha | Locale::Yo | Locale::Ig | Locale::Pcm => (',', '.'),
+        }
+    }
+
+    pub fn format_number(self, value: i64) -> String {
+        let (group, _) = self.separators();
+        let digits = value.unsigned_abs().to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+        if value < 0 {
+            out.push('-');
+        }
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                out.push(group);
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    /// "₦1,250.50"; always two decimals so prices line up on the cards
+    pub fn format_naira(self, amount: Money) -> String {
+        let (_, decimal) = self.separators();
+        let sign = if amount.kobo() < 0 { "-" } else { "" };
+        let kobo = amount.kobo().unsigned_abs();
+        format!("{sign}₦{}{decimal}{:02}", self.format_number((kobo / 100) as i64), kobo % 100)
+    }
+}
+
+/// Every user-facing string. English in `en.rs` must cover all of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Msg {
+    // App
+    Language,
+    NeedHelp,
+    Redirecting,
+    // Home
+    FindFuel,
+    Finding,
+    NoStationsFound,
+    SomethingWentWrong,
+    StationsWillAppear,
+    OutsideServiceArea,
+    NoServiceArea,
+    PriceLabel,
+    AddressLabel,
+    DirectionsLabel,
+    OpenGoogleMaps,
+    DistanceLabel,
+    NotApplicable,
+    // Signin / Signup
+    Name,
+    Address,
+    Email,
+    Phone,
+    Password,
+    RegistrationCode,
+    ShowPassword,
+    HidePassword,
+    Login,
+    Register,
+    Registering,
+    NoAccount,
+    AlreadyRegistered,
+    SignupNote,
+    RegisterStationTitle,
+    ErrorOccurred,
+    NameRequired,
+    AddressRequired,
+    EmailRequired,
+    PasswordRequired,
+    InvalidPhone,
+    EmailInvalid,
+    PhoneRequired,
+    PhoneNotMobile,
+    PasswordTooShort,
+    PasswordTooLong,
+    PasswordTooWeak,
+    PasswordWeak,
+    PasswordFair,
+    PasswordGood,
+    PasswordStrong,
+    PasswordHint,
+    CodeRequired,
+    GpsFailed,
+    // Dashboard
+    LoadingDashboard,
+    UnauthorizedRedirecting,
+    PricePerLitre,
+    Update,
+    Save,
+    Cancel,
+    Available,
+    NotAvailable,
+    UpdatePriceFirst,
+    PriceRequired,
+    PriceInvalid,
+    PriceNegative,
+    PriceTooPrecise,
+    PriceTooLarge,
+    Live,
+    Reconnecting,
+    Logout,
+    LoggingOut,
+    ActiveSessions,
+    LoadingSessions,
+    NoActiveSessions,
+    UnknownDevice,
+    ThisDevice,
+    LastActive,
+    Revoke,
+    Revoking,
+    ForgotPassword,
+    ForgotPasswordTitle,
+    ForgotPasswordHint,
+    SendResetLink,
+    Sending,
+    ResetLinkSent,
+    BackToLogin,
+    RequestNewLink,
+    ResetPasswordTitle,
+    NewPassword,
+    ConfirmPassword,
+    PasswordsDoNotMatch,
+    ResetPassword,
+    Saving,
+    PasswordResetDone,
+    ResetTokenMissing,
+    CheckEmailTitle,
+    CheckEmailBody,
+    ResendLink,
+    ResendIn,
+    LinkResent,
+    VerifyingEmail,
+    EmailVerified,
+    VerificationLinkInvalid,
+    GoToLogin,
+    GoToDashboard,
+    UnverifiedBanner,
+    NotAllowed,
+    // Admin
+    Admin,
+    AdminStations,
+    RegistrationActivity,
+    SearchStations,
+    LoadingStations,
+    Suspend,
+    Reactivate,
+    SignOutEverywhere,
+    Suspended,
+    Unverified,
+    RegisteredOn,
+    ConfirmAccountAction,
+    RegistrationsInDays,
+    RecentRegistrations,
+    // Staff
+    Staff,
+    LoadingStaff,
+    NoStaff,
+    InviteStaff,
+    StaffInvited,
+    InvitePending,
+    CanUpdatePrices,
+    CanToggleAvailability,
+    ViewOnly,
+    Remove,
+    Removing,
+    UpdatedBy,
+    AcceptInviteTitle,
+    AcceptInvite,
+    InviteAccepted,
+    InviteTokenMissing,
+    // Two-factor
+    TwoFactor,
+    TwoFactorOn,
+    TwoFactorOff,
+    SetUpTwoFactor,
+    ScanQrCode,
+    CantScan,
+    AuthCode,
+    Verify,
+    Verifying,
+    RecoveryCodesHint,
+    DownloadCodes,
+    SavedCodes,
+    EnterCodeToChange,
+    NewRecoveryCodes,
+    DisableTwoFactor,
+    TwoFactorPrompt,
+    RecoveryCodePrompt,
+    RecoveryCode,
+    UseRecoveryCode,
+    UseAuthenticator,
+    OtpInvalid,
+    RecoveryCodeRequired,
+    // Sign-in errors
+    InvalidCredentials,
+    InvalidCode,
+    TooManyAttempts,
+    AccountLocked,
+    AccountSuspended,
+    TryAgainIn,
+    // Signup location picker
+    LocationTitle,
+    LocationHint,
+    LocatingYou,
+    AccuracyWithin,
+    LocationTooImprecise,
+    LocationRequired,
+    RetryLocation,
+    ResetPin,
+    GpsPermissionDenied,
+    GpsTimeout,
+    GpsUnsupported,
+    LocationStale,
+    // Signup wizard
+    StepBusiness,
+    StepContact,
+    StepLocation,
+    StepReview,
+    Back,
+    Next,
+    Edit,
+    ReviewHint,
+    DraftRestored,
+    StartOver,
+    StepCommodities,
+    CommoditiesHint,
+    SelectCommodity,
+    PriceZero,
+    CommodityPms,
+    CommodityAgo,
+    CommodityDpk,
+    CommodityLpg,
+    // Station images
+    Photos,
+    PhotosHint,
+    StationPhoto,
+    StationLogo,
+    CropPosition,
+    ImageSizeKb,
+    ImageUnreadable,
+    ImageTooLarge,
+    SignupTooLarge,
+    Upload,
+    Uploading,
+    NoneAdded,
+    // Brands
+    Brand,
+    AllBrands,
+    BrandNnpc,
+    BrandTotalEnergies,
+    BrandConoil,
+    BrandMrs,
+    BrandIndependent,
+    NoStationsForBrand,
+    ChooseBrand,
+    // Bulk price update
+    UpdateAllPrices,
+    SaveAll,
+    NothingChanged,
+    PriceColumn,
+    PricesNotAllowed,
+}
+
+#[derive(Clone, Copy)]
+pub struct I18n {
+    locale: RwSignal<Locale>,
+}
+
+impl I18n {
+    pub fn locale(&self) -> Locale {
+        self.locale.get()
+    }
+
+    pub fn set_locale(&self, locale: Locale) {
+        if let Some(storage) = window().local_storage().ok().flatten() {
+            let _ = storage.set_item(STORAGE_KEY, locale.code());
+        }
+        self.locale.set(locale);
+    }
+
+    /// Reactive lookup, use inside view closures
+    pub fn t(&self, msg: Msg) -> &'static str {
+        self.locale.get().t(msg)
+    }
+
+    /// For async actions and event handlers where nothing should subscribe
+    pub fn t_untracked(&self, msg: Msg) -> &'static str {
+        self.locale.get_untracked().t(msg)
+    }
+
+    pub fn format_naira(&self, amount: Money) -> String {
+        self.locale.get().format_naira(amount)
+    }
+}
+
+// Saved choice first, then the browser's language, then English
+fn initial_locale() -> Locale {
+    let stored = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok().flatten());
+    stored
+        .or_else(|| window().navigator().language())
+        .and_then(|code| Locale::from_code(&code))
+        .unwrap_or_default()
+}
+
+pub fn provide_i18n() -> I18n {
+    let i18n = I18n { locale: RwSignal::new(initial_locale()) };
+    provide_context(i18n);
+    i18n
+}
+
+pub fn use_i18n() -> I18n {
+    expect_context::<I18n>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_thousands() {
+        let cases = [(0, "0"), (999, "999"), (1_000, "1,000"), (1_250_000, "1,250,000"), (-12_345, "-12,345")];
+        for (value, expected) in cases {
+            assert_eq!(Locale::En.format_number(value), expected);
+        }
+        assert_eq!(Locale::En.format_number(i64::MIN), "-9,223,372,036,854,775,808");
+    }
+
+    #[test]
+    fn formats_naira_with_two_decimals() {
+        assert_eq!(Locale::En.format_naira(Money::from_kobo(0)), "₦0.00");
+        assert_eq!(Locale::En.format_naira(Money::from_kobo(5)), "₦0.05");
+        assert_eq!(Locale::En.format_naira(Money::from_kobo(65_000)), "₦650.00");
+        assert_eq!(Locale::Yo.format_naira(Money::from_kobo(125_050)), "₦1,250.50");
+        assert_eq!(Locale::En.format_naira(Money::from_kobo(-125_050)), "-₦1,250.50");
+        assert_eq!(Locale::En.format_naira(Money::from_kobo(-5)), "-₦0.05");
+    }
+
+    #[test]
+    fn untranslated_messages_fall_back_to_english() {
+        assert_eq!(Locale::Ha.t(Msg::NothingChanged), en::message(Msg::NothingChanged));
+        assert_ne!(Locale::Ha.t(Msg::FindFuel), en::message(Msg::FindFuel));
+    }
+}
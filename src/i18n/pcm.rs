@@ -0,0 +1,51 @@
+use super::Msg;
+
+/// Nigerian Pidgin. Messages missing here read the same as English and fall back to it.
+pub fn message(msg: Msg) -> Option<&'static str> {
+    let text = match msg {
+        Msg::NeedHelp => "You need help? ",
+        Msg::Redirecting => "We dey carry you go...",
+        Msg::Finding => "We dey find am...",
+        Msg::NoStationsFound => "We no see any station for your area.",
+        Msg::SomethingWentWrong => "Wahala! Something no work ",
+        Msg::StationsWillAppear => "Stations go show for here (for now na only {regions} we dey)",
+        Msg::DirectionsLabel => "How to reach: ",
+        Msg::DistanceLabel => "How far: ",
+        Msg::NotApplicable => "E no dey",
+        Msg::Login => "Enter",
+        Msg::Registering => "We dey register am...",
+        Msg::NoAccount => "You no get account? ",
+        Msg::AlreadyRegistered => "You don register before? ",
+        Msg::SignupNote => "Abeg note: Make sure say you register for the exact place wey your station dey, because we dey save the GPS location by ourself.",
+        Msg::RegisterStationTitle => "Register Your Filling Station",
+        Msg::ErrorOccurred => "Wahala! Error happen: ",
+        Msg::NameRequired => "You need put name",
+        Msg::AddressRequired => "You need put address",
+        Msg::EmailRequired => "You need put email",
+        Msg::PasswordRequired => "You need put password",
+        Msg::InvalidPhone => "This phone number no correct",
+        Msg::InvalidCredentials => "Email or password no correct",
+        Msg::PasswordTooWeak => "This password too easy, person fit guess am",
+        Msg::CodeRequired => "You need put code",
+        Msg::GpsFailed => "We no fit get your GPS location.",
+        Msg::LoadingDashboard => "Dashboard dey load...",
+        Msg::UnauthorizedRedirecting => "You no get access - we dey carry you go...",
+        Msg::Update => "Change am",
+        Msg::Available => "E dey",
+        Msg::NotAvailable => "E no dey",
+        Msg::UpdatePriceFirst => "Abeg change the price first",
+        Msg::PriceRequired => "Abeg put price",
+        Msg::PriceNegative => "Price no fit be negative",
+        Msg::PriceTooLarge => "This price too high, check am make sure say you no add extra zero",
+        Msg::Reconnecting => "E dey connect again...",
+        Msg::Logout => "Comot",
+        Msg::NoActiveSessions => "No other phone or computer dey inside this account.",
+        Msg::ThisDevice => "Na this one",
+        Msg::ForgotPassword => "You don forget password?",
+        Msg::PasswordsDoNotMatch => "The two password no be the same",
+        Msg::CheckEmailTitle => "Check your email",
+        Msg::UnverifiedBanner => "You never confirm your email. Abeg click the link wey we send you.",
+        _ => return None,
+    };
+    Some(text)
+}
@@ -0,0 +1,55 @@
+use super::Msg;
+
+/// Yoruba.
+pub fn message(msg: Msg) -> Option<&'static str> {
+    let text = match msg {
+        Msg::Language => "Èdè",
+        Msg::NeedHelp => "Ṣé o nílò ìrànlọ́wọ́? ",
+        Msg::Redirecting => "À ń darí rẹ...",
+        Msg::FindFuel => "Wá Epo",
+        Msg::Finding => "À ń wá...",
+        Msg::NoStationsFound => "A kò rí ilé epo kankan ní agbègbè rẹ.",
+        Msg::SomethingWentWrong => "Háà! Nǹkan kan ṣàṣìṣe ",
+        Msg::StationsWillAppear => "Àwọn ilé epo yóò hàn níbí (iṣẹ́ wa wà ní {regions} nìkan báyìí)",
+        Msg::PriceLabel => "Iye owó: ",
+        Msg::AddressLabel => "Àdírẹ́sì: ",
+        Msg::DirectionsLabel => "Ìtọ́sọ́nà: ",
+        Msg::OpenGoogleMaps => "Ṣí Google Maps",
+        Msg::DistanceLabel => "Ìjìnnà: ",
+        Msg::NotApplicable => "Kò sí",
+        Msg::Name => "Orúkọ",
+        Msg::Address => "Àdírẹ́sì",
+        Msg::Email => "Ímeèlì",
+        Msg::Phone => "Fóònù",
+        Msg::Password => "Ọ̀rọ̀ aṣínà",
+        Msg::RegistrationCode => "Kóòdù Ìforúkọsílẹ̀",
+        Msg::ShowPassword => "fihàn",
+        Msg::HidePassword => "fi pamọ́",
+        Msg::Login => "Wọlé",
+        Msg::Register => "Forúkọsílẹ̀",
+        Msg::Registering => "À ń forúkọsílẹ̀...",
+        Msg::NoAccount => "Ṣé o kò ní àkáǹtì? ",
+        Msg::AlreadyRegistered => "Ṣé o ti forúkọsílẹ̀ tẹ́lẹ̀? ",
+        Msg::SignupNote => "Àkíyèsí: Jọ̀wọ́ forúkọsílẹ̀ ní ibi tí ilé epo rẹ wà gan-an, nítorí a máa ń fi ipò GPS pamọ́ láìfọwọ́yí.",
+        Msg::RegisterStationTitle => "Forúkọ Ilé Epo Sílẹ̀",
+        Msg::ErrorOccurred => "Háà! Àṣìṣe kan ṣẹlẹ̀: ",
+        Msg::NameRequired => "A nílò orúkọ",
+        Msg::AddressRequired => "A nílò àdírẹ́sì",
+        Msg::EmailRequired => "A nílò ímeèlì",
+        Msg::PasswordRequired => "A nílò ọ̀rọ̀ aṣínà",
+        Msg::InvalidPhone => "Nọ́ńbà fóònù kò tọ́",
+        Msg::CodeRequired => "A nílò kóòdù",
+        Msg::GpsFailed => "A kò lè rí ipò GPS.",
+        Msg::LoadingDashboard => "À ń gbé dátà dashboard wọlé...",
+        Msg::UnauthorizedRedirecting => "Kò ní àṣẹ - À ń darí rẹ...",
+        Msg::PricePerLitre => " - Iye owó/Lítà",
+        Msg::Update => "Ṣàtúnṣe",
+        Msg::Save => "Fi pamọ́",
+        Msg::Cancel => "Fagilé",
+        Msg::Available => "Wà",
+        Msg::NotAvailable => "Kò sí",
+        Msg::UpdatePriceFirst => "Jọ̀wọ́ ṣàtúnṣe iye owó kọ́kọ́",
+        _ => return None,
+    };
+    Some(text)
+}
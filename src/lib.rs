@@ -1,30 +1,48 @@
 use leptos::prelude::*;
 use leptos_meta::*;
-use leptos_router::hooks::use_navigate;
-use leptos_router::{StaticSegment, components::*, path};
+use leptos_router::{StaticSegment, components::*};
+use leptos_router::path;
 
+use crate::components::auto_logout::AutoLogout;
+use crate::components::language_switcher::LanguageSwitcher;
+use crate::components::protected::Protected;
+use crate::i18n::{Msg, provide_i18n};
 use crate::pages::not_found::NotFound;
 
 // Modules
 mod components;
+mod i18n;
 mod pages;
 mod utils;
 
 // Top-Level pages
+use crate::pages::admin::activity::RegistrationActivity;
+use crate::pages::admin::layout::AdminLayout;
+use crate::pages::admin::stations::AdminStations;
 use crate::pages::home::Home;
+use crate::pages::stations::accept_invite::AcceptInvite;
 use crate::pages::stations::dashboard::dashboard::StationDashboard;
+use crate::pages::stations::forgot_password::ForgotPassword;
+use crate::pages::stations::reset_password::ResetPassword;
 use crate::pages::stations::signin::Signin;
-use crate::pages::stations::signup::Signup;
-use crate::utils::protect_route::is_authenticated;
+use crate::pages::stations::signup::signup::Signup;
+use crate::pages::stations::verify_email::{CheckEmail, VerifyEmail};
+use crate::utils::session::provide_session;
+
+pub use crate::utils::app_config::AppConfig;
 
 /// An app router which renders the homepage and handles 404's
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    provide_context(AppConfig::current().clone());
+    let support = AppConfig::current().support.clone();
+    let i18n = provide_i18n();
+    let session = provide_session();
 
     view! {
-        <Html attr:lang="en" attr:dir="ltr" attr:data-theme="light" />
+        <Html attr:lang=move || i18n.locale().code() attr:dir="ltr" attr:data-theme="light" />
 
         // sets the document title
         <Title text="Welcome to Leptos CSR" />
@@ -38,24 +56,38 @@ pub fn App() -> impl IntoView {
 
         <Router>
             <img class="logo" src="assets/petrol_pump/pump_red.jpeg" alt="FuelFinder Logo"  />
+            <LanguageSwitcher />
+            {move || session.is_admin().then(|| view! {
+                <A href="/admin" attr:class="admin-link">{i18n.t(Msg::Admin)}</A>
+            })}
+            <AutoLogout />
             <Routes fallback=|| view! { <NotFound/> }>
                 <Route path=StaticSegment("/") view=Home/>
                 <Route path=StaticSegment("/signup") view=Signup/>
                 <Route path=StaticSegment("/signin") view=Signin/>
-                <Route 
-                    path=StaticSegment("/station") 
-                    view=move || {
-                        if is_authenticated() {
-                            view! { <StationDashboard/> }.into_any()
-                        } else {
-                            let navigate = use_navigate();
-                            navigate("/signin", Default::default());
-                            view! { <p>"Redirecting..."</p> }.into_any()
-                        }
-                    }
+                <Route path=StaticSegment("/forgot-password") view=ForgotPassword/>
+                <Route path=StaticSegment("/reset-password") view=ResetPassword/>
+                <Route path=StaticSegment("/check-email") view=CheckEmail/>
+                <Route path=StaticSegment("/verify-email") view=VerifyEmail/>
+                <Route path=StaticSegment("/accept-invite") view=AcceptInvite/>
+                <Route
+                    path=StaticSegment("/station")
+                    view=|| view! { <Protected><StationDashboard/></Protected> }
                 />
-               
+                <ParentRoute path=path!("/admin") view=AdminLayout>
+                    <Route path=path!("") view=AdminStations/>
+                    <Route path=path!("activity") view=RegistrationActivity/>
+                </ParentRoute>
             </Routes>
         </Router>
+
+        {(support.email.is_some() || support.phone.is_some()).then(|| view! {
+            <footer class="support-contact">
+                {move || i18n.t(Msg::NeedHelp)}
+                {support.email.map(|email| view! { <a href=format!("mailto:{email}")>{email.clone()}</a> })}
+                " "
+                {support.phone.map(|phone| view! { <a href=format!("tel:{phone}")>{phone.clone()}</a> })}
+            </footer>
+        })}
     }
 }
@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use fuelfinder_client::App;
+use fuelfinder_client::{App, AppConfig};
 use wasm_bindgen::JsCast; // Required for type casting
 use web_sys::HtmlElement; // The specific type Leptos wants
 
@@ -8,6 +8,14 @@ fn main() {
     _ = console_log::init_with_level(log::Level::Debug);
     console_error_panic_hook::set_once();
 
+    // Config has to be in place before any API call, so mount only once it's loaded
+    leptos::task::spawn_local(async {
+        AppConfig::load().await.install();
+        mount_app();
+    });
+}
+
+fn mount_app() {
     let document = document();
     // 1. Find the loading screen specifically
     if let Some(loader) = document.get_element_by_id("loading-screen") {
@@ -0,0 +1,67 @@
+use leptos::prelude::*;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::admin::dto::{AccountAction, apply_account_action, fetch_registration_activity};
+use crate::pages::admin::stations::AdminStationRow;
+use crate::utils::session::use_session;
+
+const ACTIVITY_DAYS: u32 = 30;
+
+/// Sign-ups per day over the last month, and the newest accounts to review
+#[component]
+pub fn RegistrationActivity() -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+
+    let activity_resource = LocalResource::new(move || fetch_registration_activity(session, ACTIVITY_DAYS));
+
+    let account_action = Action::new_local(move |(id, action): &(String, AccountAction)| {
+        let id = id.clone();
+        let action = *action;
+        async move {
+            apply_account_action(session, &id, action).await?;
+            activity_resource.refetch();
+            Ok::<(), String>(())
+        }
+    });
+
+    view! {
+        <section class="admin-activity">
+            <h1>{move || i18n.t(Msg::RegistrationActivity)}</h1>
+            <Suspense fallback=move || view! { <p class="loading">{i18n.t(Msg::LoadingStations)}</p> }>
+                {move || activity_resource.get().map(|res| match res {
+                    Ok(activity) => {
+                        let total: u32 = activity.days.iter().map(|d| d.count).sum();
+                        let busiest = activity.days.iter().map(|d| d.count).max().unwrap_or(0).max(1);
+                        view! {
+                            <p class="status-msg">
+                                {i18n.t(Msg::RegistrationsInDays)
+                                    .replace("{count}", &i18n.locale().format_number(total.into()))
+                                    .replace("{days}", &ACTIVITY_DAYS.to_string())}
+                            </p>
+                            <ul class="activity-chart">
+                                {activity.days.into_iter().map(|day| view! {
+                                    <li>
+                                        <span class="activity-date">{day.date}</span>
+                                        <span class="activity-bar" style=format!("width: {}%", day.count * 100 / busiest)></span>
+                                        <span class="activity-count">{day.count}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                            <h3>{i18n.t(Msg::RecentRegistrations)}</h3>
+                            <ul class="admin-station-list">
+                                {activity.recent.into_iter().map(|station| view! {
+                                    <AdminStationRow station=station account_action=account_action />
+                                }).collect_view()}
+                            </ul>
+                        }.into_any()
+                    }
+                    Err(e) => view! { <p class="error-message">{e}</p> }.into_any(),
+                })}
+            </Suspense>
+            {move || account_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{err}</small>
+            })}
+        </section>
+    }
+}
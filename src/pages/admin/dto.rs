@@ -0,0 +1,117 @@
+use gloo_net::http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Msg;
+use crate::utils::api::send_authed;
+use crate::utils::base_url::BaseUrl;
+use crate::utils::session::SessionContext;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountStatus {
+    #[default]
+    Active,
+    Suspended,
+}
+
+/// A station account as operations staff see it in `/admin`
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AdminStation {
+    pub id: String,
+    pub name: String,
+    pub address: String,
+    pub email: String,
+    pub phone: String,
+    pub role: String,
+    #[serde(default)]
+    pub status: AccountStatus,
+    #[serde(default)]
+    pub email_verified: bool,
+    pub created_at: String,
+    #[serde(default)]
+    pub last_login_at: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegistrationDay {
+    /// "2025-01-31"
+    pub date: String,
+    pub count: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegistrationActivity {
+    pub days: Vec<RegistrationDay>,
+    pub recent: Vec<AdminStation>,
+}
+
+/// What staff can do to a problem account
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountAction {
+    Suspend,
+    Reactivate,
+    SignOutEverywhere,
+    ResendVerification,
+}
+
+impl AccountAction {
+    fn path(self) -> &'static str {
+        match self {
+            AccountAction::Suspend => "suspend",
+            AccountAction::Reactivate => "reactivate",
+            AccountAction::SignOutEverywhere => "revoke-sessions",
+            AccountAction::ResendVerification => "resend-verification",
+        }
+    }
+
+    pub fn label(self) -> Msg {
+        match self {
+            AccountAction::Suspend => Msg::Suspend,
+            AccountAction::Reactivate => Msg::Reactivate,
+            AccountAction::SignOutEverywhere => Msg::SignOutEverywhere,
+            AccountAction::ResendVerification => Msg::ResendLink,
+        }
+    }
+
+    /// Actions that lock the owner out ask before going ahead
+    pub fn needs_confirmation(self) -> bool {
+        matches!(self, AccountAction::Suspend | AccountAction::SignOutEverywhere)
+    }
+}
+
+pub async fn fetch_admin_stations(session: SessionContext, search: String) -> Result<Vec<AdminStation>, String> {
+    let base_url = BaseUrl::get_base_url();
+    let search: String = js_sys::encode_uri_component(search.trim()).into();
+    let url = format!("{base_url}/api/v1/admin/stations?q={search}");
+    let resp = send_authed(session, Method::GET, &url, None).await?;
+
+    if resp.ok() {
+        resp.json::<Vec<AdminStation>>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+pub async fn fetch_registration_activity(session: SessionContext, days: u32) -> Result<RegistrationActivity, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/admin/registrations?days={days}");
+    let resp = send_authed(session, Method::GET, &url, None).await?;
+
+    if resp.ok() {
+        resp.json::<RegistrationActivity>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+pub async fn apply_account_action(session: SessionContext, station_id: &str, action: AccountAction) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/admin/stations/{station_id}/{}", action.path());
+    let resp = send_authed(session, Method::POST, &url, None).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
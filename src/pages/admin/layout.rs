@@ -0,0 +1,28 @@
+use leptos::prelude::*;
+use leptos_router::components::{A, Outlet};
+
+use crate::components::logout_button::LogoutButton;
+use crate::components::protected::Protected;
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::session::ADMIN_ROLES;
+
+/// Shell for every `/admin` page; nothing below it renders for non-admins
+#[component]
+pub fn AdminLayout() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <Protected roles=ADMIN_ROLES>
+            <div class="admin-area">
+                <div class="dashboard-header">
+                    <nav class="admin-nav">
+                        <A href="/admin" exact=true>{move || i18n.t(Msg::AdminStations)}</A>
+                        <A href="/admin/activity">{move || i18n.t(Msg::RegistrationActivity)}</A>
+                    </nav>
+                    <LogoutButton />
+                </div>
+                <Outlet />
+            </div>
+        </Protected>
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod layout;
+pub mod stations;
+pub mod activity;
@@ -0,0 +1,118 @@
+use leptos::prelude::*;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::admin::dto::{AccountAction, AccountStatus, AdminStation, apply_account_action, fetch_admin_stations};
+use crate::pages::stations::dashboard::active_sessions::short_timestamp;
+use crate::utils::session::use_session;
+
+/// Every registered station, searchable, with the account actions staff need
+#[component]
+pub fn AdminStations() -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let search = RwSignal::new(String::new());
+
+    let stations_resource = LocalResource::new(move || fetch_admin_stations(session, search.get()));
+
+    let account_action = Action::new_local(move |(id, action): &(String, AccountAction)| {
+        let id = id.clone();
+        let action = *action;
+        async move {
+            apply_account_action(session, &id, action).await?;
+            stations_resource.refetch();
+            Ok::<(), String>(())
+        }
+    });
+
+    view! {
+        <section class="admin-stations">
+            <h1>{move || i18n.t(Msg::AdminStations)}</h1>
+            <input
+                type="search"
+                class="admin-search"
+                placeholder=move || i18n.t(Msg::SearchStations)
+                // Searches on enter/blur rather than every keystroke
+                on:change=move |ev| search.set(event_target_value(&ev))
+            />
+            <Suspense fallback=move || view! { <p class="loading">{i18n.t(Msg::LoadingStations)}</p> }>
+                {move || stations_resource.get().map(|res| match res {
+                    Ok(stations) if stations.is_empty() => view! {
+                        <p class="status-msg">{i18n.t(Msg::NoStationsFound)}</p>
+                    }.into_any(),
+                    Ok(stations) => view! {
+                        <ul class="admin-station-list">
+                            {stations.into_iter().map(|station| view! {
+                                <AdminStationRow station=station account_action=account_action />
+                            }).collect_view()}
+                        </ul>
+                    }.into_any(),
+                    Err(e) => view! { <p class="error-message">{e}</p> }.into_any(),
+                })}
+            </Suspense>
+            {move || account_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{err}</small>
+            })}
+        </section>
+    }
+}
+
+#[component]
+pub fn AdminStationRow(
+    station: AdminStation,
+    account_action: Action<(String, AccountAction), Result<(), String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let mut actions = vec![match station.status {
+        AccountStatus::Active => AccountAction::Suspend,
+        AccountStatus::Suspended => AccountAction::Reactivate,
+    }];
+    actions.push(AccountAction::SignOutEverywhere);
+    if !station.email_verified {
+        actions.push(AccountAction::ResendVerification);
+    }
+
+    let id = StoredValue::new(station.id.clone());
+    let is_busy = move |action: AccountAction| {
+        account_action.pending().get()
+            && account_action.input().get().is_some_and(|(i, a)| i == id.get_value() && a == action)
+    };
+    let suspended = station.status == AccountStatus::Suspended;
+
+    view! {
+        <li class="admin-station" class:suspended=suspended>
+            <div class="session-details">
+                <strong>{station.name.clone()}</strong>
+                <small>{station.email.clone()}" · "{station.phone.clone()}</small>
+                <small>{station.address.clone()}</small>
+                <div class="admin-badges">
+                    <span class="admin-badge">{station.role.clone()}</span>
+                    {suspended.then(|| view! { <span class="admin-badge is-warning">{i18n.t(Msg::Suspended)}</span> })}
+                    {(!station.email_verified).then(|| view! { <span class="admin-badge is-warning">{i18n.t(Msg::Unverified)}</span> })}
+                </div>
+                <small>
+                    {i18n.t(Msg::RegisteredOn)}{short_timestamp(&station.created_at)}
+                    {station.last_login_at.as_deref().map(|ts| format!(" · {}{}", i18n.t(Msg::LastActive), short_timestamp(ts)))}
+                </small>
+            </div>
+            <div class="admin-actions">
+                {actions.into_iter().map(|action| view! {
+                    <button
+                        class="cancel-button"
+                        disabled=move || account_action.pending().get()
+                        on:click=move |_| {
+                            if action.needs_confirmation()
+                                && !window().confirm_with_message(i18n.t_untracked(Msg::ConfirmAccountAction)).unwrap_or(false)
+                            {
+                                return;
+                            }
+                            account_action.dispatch((id.get_value(), action));
+                        }
+                    >
+                        {move || if is_busy(action) { i18n.t(Msg::Saving) } else { i18n.t(action.label()) }}
+                    </button>
+                }).collect_view()}
+            </div>
+        </li>
+    }
+}
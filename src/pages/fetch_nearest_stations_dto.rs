@@ -1,15 +1,93 @@
 use gloo_net::http::Request;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::i18n::Msg;
 use crate::utils::base_url::BaseUrl;
+use crate::utils::money::Money;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// The marketer a station sells for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Brand {
+    Nnpc,
+    TotalEnergies,
+    Conoil,
+    Mrs,
+    #[default]
+    Independent,
+}
+
+impl Brand {
+    pub const ALL: [Brand; 5] = [Brand::Nnpc, Brand::TotalEnergies, Brand::Conoil, Brand::Mrs, Brand::Independent];
+
+    /// Same as the wire name
+    pub fn code(self) -> &'static str {
+        match self {
+            Brand::Nnpc => "nnpc",
+            Brand::TotalEnergies => "total_energies",
+            Brand::Conoil => "conoil",
+            Brand::Mrs => "mrs",
+            Brand::Independent => "independent",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Brand> {
+        Brand::ALL.into_iter().find(|b| b.code() == code)
+    }
+
+    pub fn label(self) -> Msg {
+        match self {
+            Brand::Nnpc => Msg::BrandNnpc,
+            Brand::TotalEnergies => Msg::BrandTotalEnergies,
+            Brand::Conoil => Msg::BrandConoil,
+            Brand::Mrs => Msg::BrandMrs,
+            Brand::Independent => Msg::BrandIndependent,
+        }
+    }
+
+    /// Logo served from `public/assets/brands`, for the marketers that have one.
+    /// Only used when the `brand_logos` feature is on, see the README.
+    pub fn logo_path(self) -> Option<String> {
+        (self != Brand::Independent).then(|| format!("/assets/brands/{}.svg", self.code()))
+    }
+
+    /// What fits on the round badge on a card
+    pub fn badge(self) -> &'static str {
+        match self {
+            Brand::Nnpc => "NNPC",
+            Brand::TotalEnergies => "TE",
+            Brand::Conoil => "CON",
+            Brand::Mrs => "MRS",
+            Brand::Independent => "IND",
+        }
+    }
+}
+
+/// A brand this build doesn't know yet is left out rather than guessed at,
+/// and doesn't fail the whole list either
+fn known_brand<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Brand>, D::Error> {
+    let code = Option::<String>::deserialize(deserializer)?;
+    Ok(code.as_deref().and_then(Brand::from_code))
+}
+
+/// The owner or staff account behind a commodity change
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChangeAuthor {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Commodity {
     pub id: String,
     pub name: String,
-    pub price: i32,
+    pub price: Money,
     pub station_id: String,
-    pub is_available: bool
+    pub is_available: bool,
+    #[serde(default)]
+    pub updated_by: Option<ChangeAuthor>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -19,18 +97,32 @@ pub struct Station {
     pub address: String,
     pub email: String,
     pub phone: String,
+    /// Older stations registered before brands were asked for have none
+    #[serde(default, deserialize_with = "known_brand")]
+    pub brand: Option<Brand>,
     pub latitude: f64,
     pub longitude: f64,
     pub role: String,
     pub created_at: String,
     pub updated_at: String,
     pub distance: Option<f64>,
+    /// Only sent on the owner's own dashboard; `None` everywhere else
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    /// Also dashboard-only
+    #[serde(default)]
+    pub two_factor_enabled: Option<bool>,
+    /// Uploaded by the owner; Home falls back to stock pictures without one
+    #[serde(default)]
+    pub photo_url: Option<String>,
+    #[serde(default)]
+    pub logo_url: Option<String>,
     pub commodities: Vec<Commodity>,
 }
 
 pub async fn fetch_closests(lat:f64, lon:f64) -> Result<Vec<Station>, String> {
-    let BASE_URL = BaseUrl::get_base_url();
-    let url = format!("{BASE_URL}/api/v1/stations/closest?latitude={lat}&longitude={lon}"); // Added "stations" to match typical API
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/stations/closest?latitude={lat}&longitude={lon}"); // Added "stations" to match typical API
     let request = Request::get(url.as_str()).send().await;
 
     match request {
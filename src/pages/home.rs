@@ -1,25 +1,64 @@
-use crate::pages::fetch_nearest_stations_dto::{Station, fetch_closests};
+use crate::pages::fetch_nearest_stations_dto::{Brand, Station, fetch_closests};
+use crate::components::brand_badge::BrandBadge;
 use crate::utils::get_stations_imgs::STATION_IMAGES;
 use crate::utils::get_gps_location::locate;
 use crate::utils::validate_boundary;
+use crate::utils::app_config::{AppConfig, use_app_config};
+use crate::i18n::{Msg, use_i18n};
+use crate::components::live_indicator::LiveIndicator;
+use crate::utils::live_prices::use_live_prices;
 use leptos::{logging, prelude::*};
 
+// "Nearest" only needs to be right to within a neighbourhood, and a reading
+// from a few minutes ago (e.g. the last known position) is still close enough
+const MAX_FIX_ACCURACY_M: f64 = 1_000.0;
+const MAX_FIX_AGE_SECS: f64 = 10.0 * 60.0;
+
 #[component]
 pub fn Home() -> impl IntoView {
+    let i18n = use_i18n();
     let get_stations_action = Action::new_local(move |_: &()| {
         async move {
-            if let Some((lat, lon)) = locate().await {
-                logging::log!("these are the lat an lon {}, {}", lat, lon);
-                let _ = validate_boundary::validate_abuja_bounds(lat, lon)?;
-                fetch_closests(lat, lon).await
-            } else {
-                Err("GPS took too long or permission was denied.".to_string())
+            let fix = locate().await.map_err(|e| i18n.t_untracked(e.msg()).to_string())?;
+            if fix.accuracy_m > MAX_FIX_ACCURACY_M {
+                return Err(i18n.t_untracked(Msg::LocationTooImprecise).to_string());
+            }
+            if fix.age_secs() > MAX_FIX_AGE_SECS {
+                return Err(i18n.t_untracked(Msg::LocationStale).to_string());
             }
+            let (lat, lon) = (fix.latitude, fix.longitude);
+            logging::log!("these are the lat an lon {}, {}", lat, lon);
+            validate_boundary::validate_service_area(lat, lon, &AppConfig::current().enabled_regions)
+                .map_err(|e| e.describe(i18n.t_untracked(e.msg())))?;
+            fetch_closests(lat, lon).await.map_err(|e| {
+                logging::error!("Fetching nearby stations failed: {}", e);
+                i18n.t_untracked(Msg::SomethingWentWrong).to_string()
+            })
         }
     });
 
     let stations_result = get_stations_action.value();
     let selected_station = RwSignal::new(None::<Station>);
+    // `None` shows every brand, including stations that never set one
+    let brand_filter = RwSignal::new(None::<Brand>);
+
+    // Patch prices on the cards we're already showing instead of refetching
+    let live = use_live_prices();
+    Effect::new(move |_| {
+        if let Some(change) = live.change.get() {
+            stations_result.maybe_update(|res| match res {
+                Some(Ok(stations)) => change.apply_to_all(stations),
+                _ => false,
+            });
+            selected_station.maybe_update(|sel| sel.as_mut().is_some_and(|s| change.apply_to(s)));
+        }
+    });
+
+    let service_areas = validate_boundary::enabled_areas(&use_app_config().enabled_regions)
+        .iter()
+        .map(|area| area.name)
+        .collect::<Vec<_>>()
+        .join(", ");
 
     view! {
         <div class="home-container">
@@ -29,23 +68,53 @@ pub fn Home() -> impl IntoView {
                 on:click=move |_| { get_stations_action.dispatch(()); }
             >
                 {move || if get_stations_action.pending().get() { 
-                    "Finding..." 
+                    i18n.t(Msg::Finding)
                 } else { 
-                    "Find Fuel" 
+                    i18n.t(Msg::FindFuel)
                 }}
             </button>
 
+            <LiveIndicator live=live />
+
+            <Show when=move || matches!(stations_result.get(), Some(Ok(_)))>
+                <label class="brand-filter">
+                    {move || i18n.t(Msg::Brand)}
+                    <select
+                        prop:value=move || brand_filter.get().map(Brand::code).unwrap_or_default()
+                        on:change=move |ev| {
+                            brand_filter.set(Brand::from_code(&event_target_value(&ev)));
+                            // The selected station may not be in the filtered list any more
+                            selected_station.set(None);
+                        }
+                    >
+                        <option value="">{move || i18n.t(Msg::AllBrands)}</option>
+                        {Brand::ALL.into_iter().map(|b| view! {
+                            <option value=b.code()>{move || i18n.t(b.label())}</option>
+                        }).collect_view()}
+                    </select>
+                </label>
+            </Show>
+
             <div class="results-container">
                 {move || match stations_result.get() {
                     Some(Ok(stations)) => {
-                        if stations.is_empty() {
-                            view! { <p class="status-msg">"No stations found in your area."</p> }.into_any()
+                        let found_any = !stations.is_empty();
+                        let stations = match brand_filter.get() {
+                            Some(brand) => stations.into_iter().filter(|s| s.brand == Some(brand)).collect(),
+                            None => stations,
+                        };
+                        if !found_any {
+                            view! { <p class="status-msg">{i18n.t(Msg::NoStationsFound)}</p> }.into_any()
+                        } else if stations.is_empty() {
+                            view! { <p class="status-msg">{i18n.t(Msg::NoStationsForBrand)}</p> }.into_any()
                         } else {
                             view! { 
                                 <ul class="dashboard">
                                     {stations.into_iter().enumerate().map(|(i, s)|{
-                                        // FIX 1: Use modulo (%) to prevent index out of bounds
-                                        let image_url = STATION_IMAGES[i % STATION_IMAGES.len()];
+                                        // Owner's own photo, else a stock one (modulo keeps the index in bounds)
+                                        let image_url = s.photo_url.clone()
+                                            .unwrap_or_else(|| STATION_IMAGES[i % STATION_IMAGES.len()].to_string());
+                                        let logo_url = s.logo_url.clone();
                                         let station = s.clone();
                                         let station_id = s.id.clone();
                                         
@@ -55,8 +124,10 @@ pub fn Home() -> impl IntoView {
                                                     {"card is-selected"} else {"card"}
                                                 on:click=move |_| selected_station.set(Some(station.clone()))
                                             >
-                                                <img src=image_url class="card-image" alt="Station" />
+                                                <img src=image_url class="card-image" alt="Station" loading="lazy" />
+                                                {logo_url.map(|logo| view! { <img src=logo class="card-logo" alt="" loading="lazy" /> })}
                                                 <div class="station-name">
+                                                    {s.brand.map(|brand| view! { <BrandBadge brand=brand /> })}
                                                     <p>{s.name}</p> 
                                                 </div>
                                             </li>
@@ -66,8 +137,8 @@ pub fn Home() -> impl IntoView {
                             }.into_any()
                         }
                     },
-                    Some(Err(e)) => view! { <p class="error-msg">"Oops! something went wrong "</p> }.into_any(),
-                    None => view! { <p class="status-msg">"Stations will appear here (service currently available only in Abuja)"</p> }.into_any(),
+                    Some(Err(err)) => view! { <p class="error-msg">{err}</p> }.into_any(),
+                    None => view! { <p class="status-msg">{i18n.t(Msg::StationsWillAppear).replace("{regions}", &service_areas)}</p> }.into_any(),
                 }}
             </div>
 
@@ -78,22 +149,22 @@ pub fn Home() -> impl IntoView {
                         
                         // FIX 2: Safely access commodities
                         let price = s.commodities.first()
-                            .map(|c| format!("{}", c.price))
-                            .unwrap_or_else(|| "N/A".to_string());
+                            .map(|c| i18n.format_naira(c.price))
+                            .unwrap_or_else(|| i18n.t(Msg::NotApplicable).to_string());
 
                         view! {
                             <div class="details-content">
                                 <h2>{s.name}</h2>
                                 <div class="info-section">
-                                    <div class="info-item"><strong>"Price(₦): "</strong> {price}</div>
-                                    <div class="info-item"><strong>"Address: "</strong> {s.address}</div>
+                                    <div class="info-item"><strong>{i18n.t(Msg::PriceLabel)}</strong> {price}</div>
+                                    <div class="info-item"><strong>{i18n.t(Msg::AddressLabel)}</strong> {s.address}</div>
                                     <div class="info-item">
-                                        <strong>"Directions: "</strong>
+                                        <strong>{i18n.t(Msg::DirectionsLabel)}</strong>
                                         <a href=map_url target="_blank" rel="noopener noreferrer" class="station-direction">
-                                            "Open Google Maps"
+                                            {i18n.t(Msg::OpenGoogleMaps)}
                                         </a>
                                     </div>
-                                    <div class="info-item"><strong>"Distance: "</strong> {format!("{:.2}km", s.distance.unwrap_or_else(|| 0.0))}</div>
+                                    <div class="info-item"><strong>{i18n.t(Msg::DistanceLabel)}</strong> {format!("{:.2}km", s.distance.unwrap_or(0.0))}</div>
                                 </div>
                             </div>
                         }.into_any()
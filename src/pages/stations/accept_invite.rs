@@ -0,0 +1,94 @@
+use crate::pages::stations::dto::accept_staff_invite;
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_query_map};
+
+use crate::components::form_field::{FieldError, TextInput};
+use crate::components::password_strength::PasswordStrengthMeter;
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::form::{Form, check};
+use crate::utils::validation::validate_new_password;
+
+/// Landing page for a staff invite email: `/accept-invite?token=`.
+/// The attendant picks a password, then logs in like the owner does.
+#[component]
+pub fn AcceptInvite() -> impl IntoView {
+    let i18n = use_i18n();
+    let query = use_query_map();
+    let token = move || query.read().get("token").filter(|t| !t.is_empty());
+    let show_password = RwSignal::new(false);
+
+    let accept_action = Action::new_local(move |(token, password): &(String, String)| {
+        let token = token.clone();
+        let password = password.clone();
+        async move { accept_staff_invite(token, password).await }
+    });
+
+    let form = Form::new(accept_action);
+    let password = form.field("password", check(|v: &str| validate_new_password(v).map(|_| v.to_string())));
+    let confirm_password = form.field("confirm_password", move |v: &str| {
+        if v == password.value() { Ok(()) } else { Err(Msg::PasswordsDoNotMatch) }
+    });
+    let on_submit = form.on_submit(move || {
+        confirm_password.parse()?;
+        let token = query.read_untracked().get("token").ok_or(Msg::InviteTokenMissing)?;
+        Ok((token, password.parse()?))
+    });
+
+    view! {
+        <div class="form-container">
+            <h2>{move || i18n.t(Msg::AcceptInviteTitle)}</h2>
+
+            {move || if token().is_none() {
+                view! { <p class="error-message">{i18n.t(Msg::InviteTokenMissing)}</p> }.into_any()
+            } else if matches!(accept_action.value().get(), Some(Ok(()))) {
+                view! {
+                    <p class="success-message">{i18n.t(Msg::InviteAccepted)}</p>
+                    <A href="/signin">{i18n.t(Msg::GoToLogin)}</A>
+                }.into_any()
+            } else {
+                view! {
+                    <form on:submit=on_submit>
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::Password)}</label>
+                            <div class="password-wrapper">
+                                <TextInput
+                                    field=password
+                                    kind=Signal::derive(move || if show_password.get() { "text" } else { "password" })
+                                    autocomplete="new-password"
+                                    attr:style="width: 100%; padding-right: 40px;"
+                                />
+                                <button
+                                    type="button"
+                                    class="password-toggle"
+                                    on:click=move |_| show_password.update(|v| *v = !*v)
+                                >
+                                    {move || if show_password.get() { i18n.t(Msg::HidePassword) } else { i18n.t(Msg::ShowPassword) }}
+                                </button>
+                            </div>
+                            <PasswordStrengthMeter password=Signal::derive(move || password.value()) />
+                            <FieldError field=password />
+                        </div>
+
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::ConfirmPassword)}</label>
+                            <TextInput
+                                field=confirm_password
+                                kind=Signal::derive(move || if show_password.get() { "text" } else { "password" })
+                                autocomplete="new-password"
+                            />
+                            <FieldError field=confirm_password />
+                        </div>
+
+                        <button type="submit" class="submit-button" disabled=move || form.pending()>
+                            {move || if form.pending() { i18n.t(Msg::Saving) } else { i18n.t(Msg::AcceptInvite) }}
+                        </button>
+                    </form>
+                }.into_any()
+            }}
+
+            {move || accept_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{i18n.t(Msg::ErrorOccurred)}{err}</small>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,86 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::stations::dto::{DeviceSession, fetch_sessions, revoke_session};
+use crate::utils::session::use_session;
+
+// "2025-01-31T09:15:00.000Z" -> "2025-01-31 09:15"
+pub fn short_timestamp(ts: &str) -> String {
+    ts.chars().take(16).collect::<String>().replace('T', " ")
+}
+
+/// Devices signed in to this station, each of which can be signed out remotely
+#[component]
+pub fn ActiveSessions() -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let navigate = use_navigate();
+
+    let sessions_resource = LocalResource::new(move || fetch_sessions(session));
+
+    let revoke_action = Action::new_local(move |device: &DeviceSession| {
+        let device = device.clone();
+        let navigate = navigate.clone();
+        async move {
+            revoke_session(session, &device.id).await?;
+            if device.current {
+                // Revoking ourselves is just a logout
+                session.sign_out();
+                navigate("/signin", Default::default());
+            } else {
+                sessions_resource.refetch();
+            }
+            Ok::<(), String>(())
+        }
+    });
+    let is_revoking = move |id: &str| {
+        revoke_action.pending().get() && revoke_action.input().get().is_some_and(|d| d.id == id)
+    };
+
+    view! {
+        <section class="active-sessions">
+            <h3>{move || i18n.t(Msg::ActiveSessions)}</h3>
+            <Suspense fallback=move || view! { <p class="loading">{i18n.t(Msg::LoadingSessions)}</p> }>
+                {move || sessions_resource.get().map(|res| match res {
+                    Ok(devices) if devices.is_empty() => view! {
+                        <p class="status-msg">{i18n.t(Msg::NoActiveSessions)}</p>
+                    }.into_any(),
+                    Ok(devices) => view! {
+                        <ul class="session-list">
+                            {devices.into_iter().map(|device| {
+                                let id = device.id.clone();
+                                let name = device.device.clone().unwrap_or_else(|| i18n.t(Msg::UnknownDevice).to_string());
+                                let last_active = device.last_active_at.as_deref().unwrap_or(&device.created_at).to_string();
+                                let stored = StoredValue::new(device.clone());
+                                view! {
+                                    <li class="session-item">
+                                        <div class="session-details">
+                                            <strong>{name}</strong>
+                                            {device.current.then(|| view! { <span class="session-current">{i18n.t(Msg::ThisDevice)}</span> })}
+                                            <small>
+                                                {device.ip_address.clone().map(|ip| format!("{ip} · "))}
+                                                {i18n.t(Msg::LastActive)}{short_timestamp(&last_active)}
+                                            </small>
+                                        </div>
+                                        <button
+                                            class="cancel-button"
+                                            disabled=move || revoke_action.pending().get()
+                                            on:click=move |_| { revoke_action.dispatch(stored.get_value()); }
+                                        >
+                                            {move || if is_revoking(&id) { i18n.t(Msg::Revoking) } else { i18n.t(Msg::Revoke) }}
+                                        </button>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                    }.into_any(),
+                    Err(e) => view! { <p class="error-message">{e}</p> }.into_any(),
+                })}
+            </Suspense>
+            {move || revoke_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{err}</small>
+            })}
+        </section>
+    }
+}
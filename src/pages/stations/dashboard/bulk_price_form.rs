@@ -0,0 +1,159 @@
+use leptos::prelude::*;
+
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::fetch_nearest_stations_dto::{Commodity, Station};
+use crate::pages::stations::dto::{ApiError, PriceUpdate, update_prices};
+use crate::utils::money::Money;
+use crate::utils::session::{Permission, use_session};
+
+/// One commodity's inputs, with what it started as to tell if it changed
+#[derive(Clone, Copy)]
+struct Row {
+    id: StoredValue<String>,
+    name: StoredValue<String>,
+    was_price: Money,
+    was_available: bool,
+    price: RwSignal<String>,
+    available: RwSignal<bool>,
+    error: RwSignal<Option<Msg>>,
+}
+
+impl Row {
+    fn new(commodity: &Commodity) -> Self {
+        Self {
+            id: StoredValue::new(commodity.id.clone()),
+            name: StoredValue::new(commodity.name.clone()),
+            was_price: commodity.price,
+            was_available: commodity.is_available,
+            price: RwSignal::new(commodity.price.to_input_string()),
+            available: RwSignal::new(commodity.is_available),
+            error: RwSignal::new(None),
+        }
+    }
+
+    /// The update to send, `None` if nothing changed, or why it can't be sent
+    fn update(&self) -> Result<Option<PriceUpdate>, Msg> {
+        let price = Money::parse(&self.price.get_untracked()).map_err(|e| e.msg())?;
+        let is_available = self.available.get_untracked();
+        // Same rule as the single card: nothing is on sale at ₦0
+        if is_available && !price.is_positive() {
+            return Err(Msg::UpdatePriceFirst);
+        }
+        if price == self.was_price && is_available == self.was_available {
+            return Ok(None);
+        }
+        Ok(Some(PriceUpdate { id: self.id.get_value(), price, is_available }))
+    }
+}
+
+/// Every commodity's price and availability in one form, checked together
+/// and saved as a single request
+#[component]
+pub fn BulkPriceForm(
+    commodities: Vec<Commodity>,
+    station_resource: LocalResource<Result<Station, String>>,
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let rows = StoredValue::new(commodities.iter().map(Row::new).collect::<Vec<_>>());
+    let can_price = move || session.can(Permission::UpdatePrices);
+    let can_toggle = move || session.can(Permission::ToggleAvailability);
+    let nothing_changed = RwSignal::new(false);
+
+    let save_action = Action::new_local(move |updates: &Vec<PriceUpdate>| {
+        let updates = updates.clone();
+        async move {
+            update_prices(session, updates).await?;
+            station_resource.refetch();
+            on_close.run(());
+            Ok::<(), ApiError>(())
+        }
+    });
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if save_action.pending().get_untracked() {
+            return;
+        }
+        // Check every row before sending any, so all the problems show at once
+        let mut updates = Vec::new();
+        let mut all_valid = true;
+        rows.with_value(|rows| {
+            for row in rows {
+                match row.update() {
+                    Ok(update) => {
+                        row.error.set(None);
+                        updates.extend(update);
+                    }
+                    Err(msg) => {
+                        row.error.set(Some(msg));
+                        all_valid = false;
+                    }
+                }
+            }
+        });
+        nothing_changed.set(all_valid && updates.is_empty());
+        if all_valid && !updates.is_empty() {
+            save_action.dispatch(updates);
+        }
+    };
+
+    view! {
+        <form class="bulk-price-form" on:submit=on_submit>
+            <table>
+                <thead>
+                    <tr>
+                        <th></th>
+                        <th>{move || i18n.t(Msg::PriceColumn)}</th>
+                        <th>{move || i18n.t(Msg::Available)}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {rows.get_value().into_iter().map(|row| view! {
+                        <tr class:has-error=move || row.error.with(Option::is_some)>
+                            <th scope="row">{row.name.get_value().to_uppercase()}</th>
+                            <td>
+                                <input
+                                    type="text"
+                                    inputmode="decimal"
+                                    class="price-input"
+                                    prop:value=move || row.price.get()
+                                    disabled=move || !can_price()
+                                    on:input=move |ev| row.price.set(event_target_value(&ev))
+                                />
+                                {move || row.error.get().map(|msg| view! {
+                                    <small class="error-message">{i18n.t(msg)}</small>
+                                })}
+                            </td>
+                            <td>
+                                <input
+                                    type="checkbox"
+                                    prop:checked=move || row.available.get()
+                                    disabled=move || !can_toggle()
+                                    on:change=move |ev| row.available.set(event_target_checked(&ev))
+                                />
+                            </td>
+                        </tr>
+                    }).collect_view()}
+                </tbody>
+            </table>
+
+            <Show when=move || nothing_changed.get()>
+                <small class="status-msg">{move || i18n.t(Msg::NothingChanged)}</small>
+            </Show>
+            {move || save_action.value().get().and_then(Result::err).map(|err| view! {
+                <small class="error-message">{err.text(i18n)}</small>
+            })}
+
+            <div class="bulk-price-actions">
+                <button type="submit" class="save-button" disabled=move || save_action.pending().get()>
+                    {move || if save_action.pending().get() { i18n.t(Msg::Saving) } else { i18n.t(Msg::SaveAll) }}
+                </button>
+                <button type="button" class="cancel-button" on:click=move |_| on_close.run(())>
+                    {move || i18n.t(Msg::Cancel)}
+                </button>
+            </div>
+        </form>
+    }
+}
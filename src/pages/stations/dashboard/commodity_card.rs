@@ -1,23 +1,39 @@
-use gloo_net::http::Request;
+use gloo_net::http::Method;
 use leptos::prelude::*;
-use crate::{pages::{fetch_nearest_stations_dto::{Commodity, Station}, stations::dashboard::utils::get_token}, 
-utils::base_url::BaseUrl};
+use crate::{pages::fetch_nearest_stations_dto::{Commodity, Station}, 
+utils::{api::send_authed, base_url::BaseUrl, money::Money, session::{Permission, use_session}}};
+use crate::pages::stations::dashboard::active_sessions::short_timestamp;
+use crate::i18n::{Msg, use_i18n};
 
+/// One commodity's price and availability. `commodity` follows the server
+/// (refetches and live updates); what the owner is typing stays put.
 #[component]
 pub fn CommodityCard(
-    commodity: Commodity, 
-    update_action: Action<(String, i32), Result<(), String>>,
+    #[prop(into)] commodity: Signal<Commodity>,
+    update_action: Action<(String, Money), Result<(), String>>,
     station_resource: LocalResource<Result<Station, String>>
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
     let is_editing = RwSignal::new(false);
-    let price_input = RwSignal::new(commodity.price.to_string());
+    let price_input = RwSignal::new(String::new());
     let error_msg = RwSignal::new(None::<String>);
  
-    //  Extract static data and Store these so they are "Copy" and accessible in any closure below
-    let stored_id = StoredValue::new(commodity.id.clone());
-    let stored_price = StoredValue::new(commodity.price);
-    let commodity_name = commodity.name.clone();
-    let is_available = RwSignal::new(commodity.is_available);
+    // The id and name never change for a card
+    let stored_id = StoredValue::new(commodity.with_untracked(|c| c.id.clone()));
+    let commodity_name = commodity.with_untracked(|c| c.name.clone());
+    let price = move || commodity.with(|c| c.price);
+    // Flipped straight away when toggled, then brought back in line with the server
+    let is_available = RwSignal::new(commodity.with_untracked(|c| c.is_available));
+    Effect::new(move |_| is_available.set(commodity.with(|c| c.is_available)));
+    let can_update_price = move || session.can(Permission::UpdatePrices);
+    let can_toggle = move || session.can(Permission::ToggleAvailability);
+    let last_change = move || commodity.with(|c| {
+        c.updated_by.as_ref().map(|author| {
+            let when = c.updated_at.as_deref().map(short_timestamp).unwrap_or_default();
+            (author.name.clone(), when)
+        })
+    });
 
     // Derived signal for the spinner
     let is_updating_this = move || {
@@ -29,18 +45,14 @@ pub fn CommodityCard(
         let id = id.clone();
         let status = *status;
         async move {
-            let token = get_token();
             let base_url = BaseUrl::get_base_url();
             let url = format!("{base_url}/api/v1/commodities/{}", id);
             let body = serde_json::json!({ "price": 0, "isAvailable": status });
-            
-            Request::patch(&url)
-                .header("Authorization", &format!("Bearer {token}"))
-                .json(&body)
-                .map_err(|e| e.to_string())?
-                .send()
-                .await
-                .map_err(|e| e.to_string())?;
+
+            let resp = send_authed(session, Method::PATCH, &url, Some(body)).await?;
+            if !resp.ok() {
+                return Err(format!("Server error: {}", resp.status()));
+            }
         
             station_resource.refetch(); 
             Ok(())
@@ -54,7 +66,7 @@ pub fn CommodityCard(
     view! {
         <div class="station-card">
             <div class="card-header">
-                <h2>{move || commodity_name.to_uppercase()} " - Price/Litre"</h2>
+                <h2>{move || commodity_name.to_uppercase()} {move || i18n.t(Msg::PricePerLitre)}</h2>
                 
                 <Show 
                     when=move || is_editing.get()
@@ -66,39 +78,47 @@ pub fn CommodityCard(
                                     view! { <div class="price-spinner"></div> }.into_any()
                                 } else {
                                     view! { 
-                                        <div><p class="price">"₦" {stored_price.get_value().to_string()}</p></div> 
+                                        <div><p class="price">{i18n.format_naira(price())}</p></div> 
                                     }.into_any()
                                 }}
                             </div>
                             <button 
                                 class="edit-button" 
-                                disabled=move || is_updating_this()
-                                on:click=move |_| is_editing.set(true)
+                                disabled=move || is_updating_this() || !can_update_price()
+                                on:click=move |_| {
+                                    price_input.set(commodity.with_untracked(|c| c.price.to_input_string()));
+                                    is_editing.set(true);
+                                }
                             >
-                                "Update"
+                                {move || i18n.t(Msg::Update)}
                             </button>
                         }
                     }
                 >
                     <div class="edit-section">
                         <input 
-                            type="number" 
+                            type="text" 
+                            inputmode="decimal"
                             class="price-input"
                             prop:value=move || price_input.get()
                             on:input=move |ev| price_input.set(event_target_value(&ev))
                         />
                         <button class="save-button" 
                             on:click=move |_| {
-                                if let Ok(new_val) = price_input.get().parse::<i32>() {
-                                    update_action.dispatch((stored_id.get_value(), new_val));
-                                    is_editing.set(false);
+                                match Money::parse(&price_input.get()) {
+                                    Ok(new_val) => {
+                                        update_action.dispatch((stored_id.get_value(), new_val));
+                                        is_editing.set(false);
+                                        error_msg.set(None);
+                                    }
+                                    Err(e) => error_msg.set(Some(i18n.t_untracked(e.msg()).into())),
                                 }
                             }
                         >
-                            "Save"
+                            {move || i18n.t(Msg::Save)}
                         </button>
                         <button class="cancel-button" on:click=move |_| is_editing.set(false)>
-                            "Cancel"
+                            {move || i18n.t(Msg::Cancel)}
                         </button>
                     </div>
                 </Show>
@@ -106,7 +126,7 @@ pub fn CommodityCard(
             <div class="card-body">
                 <div class="availability-status">
                     <span class=move || format!("status-dot {}", if is_available.get() { "available" } else { "unavailable" })></span>
-                    <span>{move || if is_available.get() { "Available" } else { "Not Available" }}</span>
+                    <span>{move || if is_available.get() { i18n.t(Msg::Available) } else { i18n.t(Msg::NotAvailable) }}</span>
                 </div>
                 
                 <div class="availability-toggle">
@@ -114,10 +134,11 @@ pub fn CommodityCard(
                         <input 
                             type="checkbox" 
                             prop:checked=move || is_available.get()
+                            disabled=move || !can_toggle()
                             on:change=move |ev| {
                                 let checked = event_target_checked(&ev);
-                                if checked && stored_price.get_value() <= 0 {
-                                    error_msg.set(Some("Please update price first".into()));
+                                if checked && !commodity.with_untracked(|c| c.price.is_positive()) {
+                                    error_msg.set(Some(i18n.t_untracked(Msg::UpdatePriceFirst).into()));
                                     is_available.set(false);
                                 } else {
                                     is_available.set(checked);
@@ -130,6 +151,11 @@ pub fn CommodityCard(
                     </label>
                 </div>
             </div>
+            {move || last_change().map(|(name, when)| view! {
+                <small class="commodity-updated-by">
+                    {move || i18n.t(Msg::UpdatedBy).replace("{name}", &name)}" · "{when}
+                </small>
+            })}
             {move || error_msg.get().map(|err| view! { <p class="error-message">{err}</p> })}
         </div>
     }
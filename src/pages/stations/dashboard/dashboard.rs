@@ -1,51 +1,74 @@
-use gloo_net::http::Request;
+use gloo_net::http::Method;
 use leptos::prelude::*;
 use leptos_router::hooks::use_navigate;
 
 use crate::pages::fetch_nearest_stations_dto::Station;
 // Adjust this import path to where your CommodityCard is located
 use crate::pages::stations::dashboard::commodity_card::CommodityCard;
-use crate::pages::stations::dashboard::utils::get_token;
+use crate::utils::api::send_authed;
 use crate::utils::base_url::BaseUrl;
+use crate::utils::protect_route::signin_redirect;
+use crate::utils::session::{Permission, use_session};
+use crate::utils::money::Money;
+use crate::i18n::{Msg, use_i18n};
+use crate::components::live_indicator::LiveIndicator;
+use crate::components::logout_button::LogoutButton;
+use crate::pages::stations::dashboard::active_sessions::ActiveSessions;
+use crate::pages::stations::dashboard::bulk_price_form::BulkPriceForm;
+use crate::pages::stations::dashboard::staff_accounts::StaffAccounts;
+use crate::pages::stations::dashboard::station_images::StationImages;
+use crate::pages::stations::dashboard::two_factor::TwoFactorSettings;
+use crate::pages::stations::verify_email::UnverifiedBanner;
+use crate::utils::live_prices::use_live_prices;
+
+/// A value from the first successful dashboard load. It stops tracking once
+/// set, so panels that keep their own state only need it to start from.
+fn first_loaded<T>(
+    resource: LocalResource<Result<Station, String>>,
+    pick: impl Fn(&Station) -> T + Send + Sync + 'static,
+) -> Memo<Option<T>>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    Memo::new(move |prev: Option<&Option<T>>| match prev {
+        Some(Some(value)) => Some(value.clone()),
+        _ => resource.get().and_then(Result::ok).map(|station| pick(&station)),
+    })
+}
 
 #[component]
 pub fn StationDashboard() -> impl IntoView {
     let navigate = use_navigate();
+    let i18n = use_i18n();
+    let session = use_session();
 
     // LocalResource handles browser-only types (like localStorage) safely
-    let station_resource = LocalResource::new(|| async move {
-        let token = get_token();
-        let BASE_URL = BaseUrl::get_base_url();
-        let url = format!("{BASE_URL}/api/v1/stations/dashboard");
-        let resp = Request::get(&url)
-            .header("Authorization", &format!("Bearer {token}"))
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
-        
+    let station_resource = LocalResource::new(move || async move {
+        let base_url = BaseUrl::get_base_url();
+        let url = format!("{base_url}/api/v1/stations/dashboard");
+        let resp = send_authed(session, Method::GET, &url, None).await?;
+        if !resp.ok() {
+            return Err(format!("Server error: {}", resp.status()));
+        }
+
         resp.json::<Station>().await.map_err(|e| e.to_string())
     });
 
     // Action for updating prices - remains local for WASM compatibility
-    let update_price_action = Action::new_local(move |(id, new_price): &(String, i32)| {
+    let update_price_action = Action::new_local(move |(id, new_price): &(String, Money)| {
         let id = id.clone();
         let price = *new_price;
-        let mut status = true;
-        if price == 0 {status = false;}
+        let status = !price.is_zero();
 
         async move {
-            let BASE_URL = BaseUrl::get_base_url();
-            let url = format!("{BASE_URL}/api/v1/commodities/{}", id);
+            let base_url = BaseUrl::get_base_url();
+            let url = format!("{base_url}/api/v1/commodities/{}", id);
             let body = serde_json::json!({ "price": price, "is_available": status });
-            let token = get_token();
-            
-            let _ = Request::patch(&url)
-                .header("Authorization", &format!("Bearer {token}"))
-                .json(&body)
-                .map_err(|e| e.to_string())?
-                .send()
-                .await
-                .map_err(|e| e.to_string())?;
+
+            let resp = send_authed(session, Method::PATCH, &url, Some(body)).await?;
+            if !resp.ok() {
+                return Err(format!("Server error: {}", resp.status()));
+            }
             
             // Refetch the data so the UI updates with the new server state
             station_resource.refetch(); 
@@ -53,34 +76,105 @@ pub fn StationDashboard() -> impl IntoView {
         }
     });
 
+    let is_owner = Memo::new(move |_| session.is_owner());
+
+    let image_urls = first_loaded(station_resource, |s| (s.photo_url.clone(), s.logo_url.clone()));
+    let verification = first_loaded(station_resource, |s| (s.email_verified, s.email.clone()));
+    let two_factor_enabled = first_loaded(station_resource, |s| s.two_factor_enabled.unwrap_or(false));
+
+    // Every price in one form instead of a card at a time
+    let bulk_mode = RwSignal::new(false);
+
+    // Changes made from another device (or by staff) show up without a refetch
+    let live = use_live_prices();
+    Effect::new(move |_| {
+        if let Some(change) = live.change.get() {
+            station_resource.maybe_update(|res| match res {
+                Some(Ok(station)) => change.apply_to(station),
+                _ => false,
+            });
+        }
+    });
+
     view! {
         <div class="station-dashboard">
-            <Suspense fallback=move || view! { <p class="loading">"Loading dashboard data..."</p> }>
+            <div class="dashboard-header">
+                <LiveIndicator live=live />
+                <LogoutButton />
+            </div>
+            // Mounted once, so the resend cooldown survives live updates and refetches
+            {move || verification.get().and_then(|(verified, email)| {
+                (verified == Some(false)).then(|| view! { <UnverifiedBanner email=email /> })
+            })}
+            <Suspense fallback=move || view! { <p class="loading">{i18n.t(Msg::LoadingDashboard)}</p> }>
                 {move || station_resource.get().map(|res| match res {
-                    Ok(data) => view! {
-                        <h1>{data.name}</h1>
-                        <div class="commodities-grid">
-                            <For
-                                each=move || data.commodities.clone()
-                                key=|c| c.id.clone()
-                                children=move |commodity| {
-                                    view! { 
-                                        <CommodityCard 
-                                            commodity=commodity 
-                                            update_action=update_price_action
-                                            station_resource=station_resource 
-                                        /> 
-                                    }
-                                }
-                            />
-                        </div>
-                    }.into_any(),
+                    Ok(data) => view! { <h1>{data.name}</h1> }.into_any(),
                     Err(_) => {
-                        navigate("/signin", Default::default());
-                        view! { <p>"Unauthorized - Redirecting..."</p> }.into_any()
+                        navigate(&signin_redirect("/station"), Default::default());
+                        view! { <p>{i18n.t(Msg::UnauthorizedRedirecting)}</p> }.into_any()
                     }
                 })}
             </Suspense>
+            // The cards live outside the station data and are keyed by id, so
+            // live updates and refetches patch them instead of rebuilding them
+            // and throwing away an edit in progress
+            <Show when=move || !bulk_mode.get() && matches!(station_resource.get(), Some(Ok(_)))>
+                <button
+                    class="edit-button bulk-price-toggle"
+                    disabled=move || !(session.can(Permission::UpdatePrices) || session.can(Permission::ToggleAvailability))
+                    on:click=move |_| bulk_mode.set(true)
+                >
+                    {move || i18n.t(Msg::UpdateAllPrices)}
+                </button>
+                <div class="commodities-grid">
+                    <For
+                        each=move || station_resource.get().and_then(Result::ok).map(|s| s.commodities).unwrap_or_default()
+                        key=|c| c.id.clone()
+                        children=move |commodity| {
+                            let id = commodity.id.clone();
+                            let commodity = Memo::new(move |_| {
+                                station_resource
+                                    .get()
+                                    .and_then(Result::ok)
+                                    .and_then(|s| s.commodities.into_iter().find(|c| c.id == id))
+                                    .unwrap_or_else(|| commodity.clone())
+                            });
+                            view! {
+                                <CommodityCard
+                                    commodity=commodity
+                                    update_action=update_price_action
+                                    station_resource=station_resource
+                                />
+                            }
+                        }
+                    />
+                </div>
+            </Show>
+            <Show when=move || bulk_mode.get()>
+                // Starts from the prices as they were when bulk mode opened; live
+                // updates and refetches don't touch the owner's unsaved edits
+                {move || {
+                    let commodities = station_resource
+                        .get_untracked()
+                        .and_then(Result::ok)
+                        .map(|station| station.commodities)
+                        .unwrap_or_default();
+                    view! {
+                        <BulkPriceForm
+                            commodities=commodities
+                            station_resource=station_resource
+                            on_close=Callback::new(move |_| bulk_mode.set(false))
+                        />
+                    }
+                }}
+            </Show>
+            // Outside the station data so saves and live updates don't reload these
+            {move || is_owner.get().then(|| image_urls.get()).flatten().map(|(photo_url, logo_url)| view! {
+                <StationImages photo_url=photo_url logo_url=logo_url station_resource=station_resource />
+            })}
+            {move || two_factor_enabled.get().map(|enabled| view! { <TwoFactorSettings enabled=enabled /> })}
+            <ActiveSessions />
+            {move || is_owner.get().then(|| view! { <StaffAccounts /> })}
         </div>
     }
 }
\ No newline at end of file
@@ -1,3 +1,9 @@
+#[allow(clippy::module_inception)]
 pub mod dashboard;
 pub mod commodity_card;
-pub mod utils;
\ No newline at end of file
+
+pub mod active_sessions;
+pub mod staff_accounts;
+pub mod two_factor;
+pub mod station_images;
+pub mod bulk_price_form;
@@ -0,0 +1,190 @@
+use leptos::prelude::*;
+
+use crate::components::form_field::{FieldError, TextInput};
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::stations::dto::{StaffInvite, StaffMember, fetch_staff, invite_staff, remove_staff, update_staff_permissions};
+use crate::utils::form::{Form, check, required};
+use crate::utils::session::{Permission, use_session};
+use crate::utils::validation::validate_email;
+
+fn permission_label(permission: Permission) -> Msg {
+    match permission {
+        Permission::UpdatePrices => Msg::CanUpdatePrices,
+        Permission::ToggleAvailability => Msg::CanToggleAvailability,
+    }
+}
+
+/// Owner-only: attendant accounts, what each may change, and inviting new ones
+#[component]
+pub fn StaffAccounts() -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+
+    let staff_resource = LocalResource::new(move || fetch_staff(session));
+
+    let invite_action = Action::new_local(move |invite: &StaffInvite| {
+        let invite = invite.clone();
+        async move {
+            invite_staff(session, invite).await?;
+            staff_resource.refetch();
+            Ok::<(), String>(())
+        }
+    });
+    let permissions_action = Action::new_local(move |(id, permissions): &(String, Vec<Permission>)| {
+        let id = id.clone();
+        let permissions = permissions.clone();
+        async move {
+            update_staff_permissions(session, &id, permissions).await?;
+            staff_resource.refetch();
+            Ok::<(), String>(())
+        }
+    });
+    let remove_action = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        async move {
+            remove_staff(session, &id).await?;
+            staff_resource.refetch();
+            Ok::<(), String>(())
+        }
+    });
+
+    let form = Form::new(invite_action);
+    let name = form.field("name", required(Msg::NameRequired));
+    let email = form.field("email", check(validate_email));
+    // New attendants start able to update prices, the usual reason for an account
+    let granted = RwSignal::new(vec![Permission::UpdatePrices]);
+    let on_submit = form.on_submit(move || Ok(StaffInvite {
+        name: name.parse()?,
+        email: email.parse()?,
+        permissions: granted.get_untracked(),
+    }));
+    Effect::new(move |_| {
+        if let Some(Ok(())) = invite_action.value().get() {
+            form.reset();
+            granted.set(vec![Permission::UpdatePrices]);
+        }
+    });
+
+    view! {
+        <section class="staff-accounts">
+            <h3>{move || i18n.t(Msg::Staff)}</h3>
+            <Suspense fallback=move || view! { <p class="loading">{i18n.t(Msg::LoadingStaff)}</p> }>
+                {move || staff_resource.get().map(|res| match res {
+                    Ok(staff) if staff.is_empty() => view! {
+                        <p class="status-msg">{i18n.t(Msg::NoStaff)}</p>
+                    }.into_any(),
+                    Ok(staff) => view! {
+                        <ul class="session-list">
+                            {staff.into_iter().map(|member| view! {
+                                <StaffRow member=member permissions_action=permissions_action remove_action=remove_action />
+                            }).collect_view()}
+                        </ul>
+                    }.into_any(),
+                    Err(e) => view! { <p class="error-message">{e}</p> }.into_any(),
+                })}
+            </Suspense>
+            {move || permissions_action.value().get().and_then(|res| res.err())
+                .or_else(|| remove_action.value().get().and_then(|res| res.err()))
+                .map(|err| view! { <small class="error-message">{err}</small> })}
+
+            <form class="staff-invite" on:submit=on_submit>
+                <h4>{move || i18n.t(Msg::InviteStaff)}</h4>
+                <div class="form-group">
+                    <label>{move || i18n.t(Msg::Name)}</label>
+                    <TextInput field=name />
+                    <FieldError field=name />
+                </div>
+                <div class="form-group">
+                    <label>{move || i18n.t(Msg::Email)}</label>
+                    <TextInput field=email kind="email" autocomplete="off" />
+                    <FieldError field=email />
+                </div>
+                <div class="form-group staff-permissions">
+                    {Permission::ALL.into_iter().map(|permission| view! {
+                        <label>
+                            <input
+                                type="checkbox"
+                                prop:checked=move || granted.with(|g| g.contains(&permission))
+                                on:change=move |ev| granted.update(|g| {
+                                    g.retain(|p| *p != permission);
+                                    if event_target_checked(&ev) {
+                                        g.push(permission);
+                                    }
+                                })
+                            />
+                            {move || i18n.t(permission_label(permission))}
+                        </label>
+                    }).collect_view()}
+                    {move || granted.with(Vec::is_empty).then(|| view! { <small>{i18n.t(Msg::ViewOnly)}</small> })}
+                </div>
+                <button type="submit" class="submit-button" disabled=move || form.pending()>
+                    {move || if form.pending() { i18n.t(Msg::Sending) } else { i18n.t(Msg::InviteStaff) }}
+                </button>
+                {move || invite_action.value().get().map(|res| match res {
+                    Ok(()) => view! { <small class="success-message">{i18n.t(Msg::StaffInvited)}</small> }.into_any(),
+                    Err(err) => view! { <small class="error-message">{err}</small> }.into_any(),
+                })}
+            </form>
+        </section>
+    }
+}
+
+#[component]
+fn StaffRow(
+    member: StaffMember,
+    permissions_action: Action<(String, Vec<Permission>), Result<(), String>>,
+    remove_action: Action<String, Result<(), String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let id = StoredValue::new(member.id.clone());
+    // What the API has saved, and what the checkboxes show while a change is on its way
+    let current = StoredValue::new(member.permissions.clone());
+    let granted = RwSignal::new(member.permissions.clone());
+    // A failed save leaves the boxes as they were clicked, so put them back.
+    // Rows with nothing in flight already match, so resetting every row is fine.
+    Effect::new(move |_| {
+        if let Some(Err(_)) = permissions_action.value().get() {
+            granted.set(current.get_value());
+        }
+    });
+    let is_removing = move || remove_action.pending().get() && remove_action.input().get().is_some_and(|i| i == id.get_value());
+
+    view! {
+        <li class="session-item">
+            <div class="session-details">
+                <strong>{member.name.clone()}</strong>
+                {member.invite_pending.then(|| view! { <span class="session-current">{i18n.t(Msg::InvitePending)}</span> })}
+                <small>{member.email.clone()}</small>
+                <div class="staff-permissions">
+                    {Permission::ALL.into_iter().map(|permission| view! {
+                        <label>
+                            <input
+                                type="checkbox"
+                                prop:checked=move || granted.with(|g| g.contains(&permission))
+                                disabled=move || permissions_action.pending().get()
+                                on:change=move |ev| {
+                                    granted.update(|g| {
+                                        g.retain(|p| *p != permission);
+                                        if event_target_checked(&ev) {
+                                            g.push(permission);
+                                        }
+                                    });
+                                    permissions_action.dispatch((id.get_value(), granted.get_untracked()));
+                                }
+                            />
+                            {move || i18n.t(permission_label(permission))}
+                        </label>
+                    }).collect_view()}
+                    {move || granted.with(Vec::is_empty).then(|| view! { <small>{i18n.t(Msg::ViewOnly)}</small> })}
+                </div>
+            </div>
+            <button
+                class="cancel-button"
+                disabled=move || remove_action.pending().get()
+                on:click=move |_| { remove_action.dispatch(id.get_value()); }
+            >
+                {move || if is_removing() { i18n.t(Msg::Removing) } else { i18n.t(Msg::Remove) }}
+            </button>
+        </li>
+    }
+}
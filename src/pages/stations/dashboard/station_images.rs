@@ -0,0 +1,73 @@
+use leptos::prelude::*;
+
+use crate::components::image_picker::ImagePicker;
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::fetch_nearest_stations_dto::Station;
+use crate::pages::stations::dto::{ApiError, upload_station_image};
+use crate::utils::image_resize::ImageKind;
+use crate::utils::session::use_session;
+
+/// One image with its own upload button, so a photo can go up without a logo
+#[component]
+fn ImageUpload(
+    kind: ImageKind,
+    label: Msg,
+    current: Option<String>,
+    station_resource: LocalResource<Result<Station, String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let picked = RwSignal::new(None::<String>);
+
+    let upload_action = Action::new_local(move |data_url: &String| {
+        let data_url = data_url.clone();
+        async move {
+            upload_station_image(session, kind, data_url).await?;
+            station_resource.refetch();
+            Ok::<(), ApiError>(())
+        }
+    });
+
+    view! {
+        <div class="image-upload">
+            <h4>{move || i18n.t(label)}</h4>
+            <ImagePicker kind=kind value=picked current=current />
+            <Show when=move || picked.with(Option::is_some)>
+                <button
+                    class="save-button"
+                    disabled=move || upload_action.pending().get()
+                    on:click=move |_| {
+                        if let Some(data_url) = picked.get_untracked() {
+                            upload_action.dispatch(data_url);
+                        }
+                    }
+                >
+                    {move || if upload_action.pending().get() { i18n.t(Msg::Uploading) } else { i18n.t(Msg::Upload) }}
+                </button>
+            </Show>
+            {move || upload_action.value().get().and_then(Result::err).map(|err| view! {
+                <small class="error-message">{err.text(i18n)}</small>
+            })}
+        </div>
+    }
+}
+
+/// Owner-only: the photo and logo drivers see on Home
+#[component]
+pub fn StationImages(
+    photo_url: Option<String>,
+    logo_url: Option<String>,
+    station_resource: LocalResource<Result<Station, String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <section class="station-images">
+            <h3>{move || i18n.t(Msg::Photos)}</h3>
+            <div class="image-uploads">
+                <ImageUpload kind=ImageKind::Photo label=Msg::StationPhoto current=photo_url station_resource=station_resource />
+                <ImageUpload kind=ImageKind::Logo label=Msg::StationLogo current=logo_url station_resource=station_resource />
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,180 @@
+use leptos::prelude::*;
+
+use crate::components::form_field::{FieldError, TextInput};
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::stations::dto::{
+    TwoFactorSetup, disable_two_factor, enable_two_factor, regenerate_recovery_codes, start_two_factor_setup,
+};
+use crate::utils::form::{Form, check};
+use crate::utils::qr::qr_svg;
+use crate::utils::session::use_session;
+use crate::utils::validation::validate_otp;
+
+#[derive(Clone, PartialEq)]
+enum Step {
+    Idle,
+    Enrolling(TwoFactorSetup),
+    /// Shown once, straight after they're generated
+    RecoveryCodes(Vec<String>),
+}
+
+/// What the manage form's code is for, set by whichever button submitted it
+#[derive(Clone, Copy, PartialEq)]
+enum Change {
+    Disable,
+    NewRecoveryCodes,
+}
+
+// "JBSWY3DPEHPK3PXP" -> "JBSW Y3DP EHPK 3PXP", easier to copy into an app by hand
+fn group_secret(secret: &str) -> String {
+    secret.as_bytes().chunks(4).map(|c| String::from_utf8_lossy(c).into_owned()).collect::<Vec<_>>().join(" ")
+}
+
+/// TOTP enrolment, recovery codes and turning 2FA off again
+#[component]
+pub fn TwoFactorSettings(enabled: bool) -> impl IntoView {
+    let i18n = use_i18n();
+    let session = use_session();
+    let enabled = RwSignal::new(enabled);
+    let step = RwSignal::new(Step::Idle);
+
+    let setup_action = Action::new_local(move |_: &()| async move {
+        let setup = start_two_factor_setup(session).await?;
+        step.set(Step::Enrolling(setup));
+        Ok::<(), String>(())
+    });
+
+    let enable_action = Action::new_local(move |code: &String| {
+        let code = code.clone();
+        async move {
+            let codes = enable_two_factor(session, code).await?;
+            enabled.set(true);
+            step.set(Step::RecoveryCodes(codes.recovery_codes));
+            Ok::<(), String>(())
+        }
+    });
+    let enable_form = Form::new(enable_action);
+    let enable_code = enable_form.field("code", check(validate_otp));
+    let on_enable = enable_form.on_submit(move || enable_code.parse());
+
+    let change = RwSignal::new(Change::NewRecoveryCodes);
+    let manage_action = Action::new_local(move |(change, code): &(Change, String)| {
+        let change = *change;
+        let code = code.clone();
+        async move {
+            match change {
+                Change::Disable => {
+                    disable_two_factor(session, code).await?;
+                    enabled.set(false);
+                }
+                Change::NewRecoveryCodes => {
+                    let codes = regenerate_recovery_codes(session, code).await?;
+                    step.set(Step::RecoveryCodes(codes.recovery_codes));
+                }
+            }
+            Ok::<(), String>(())
+        }
+    });
+    let manage_form = Form::new(manage_action);
+    let manage_code = manage_form.field("code", check(validate_otp));
+    let on_manage = manage_form.on_submit(move || Ok((change.get_untracked(), manage_code.parse()?)));
+
+    // Codes typed for one step shouldn't linger into the next
+    Effect::new(move |_| {
+        step.track();
+        enabled.track();
+        enable_form.reset();
+        manage_form.reset();
+    });
+
+    let error = move || {
+        [setup_action.value().get(), enable_action.value().get(), manage_action.value().get()]
+            .into_iter()
+            .flatten()
+            .find_map(Result::err)
+    };
+
+    view! {
+        <section class="two-factor">
+            <h3>{move || i18n.t(Msg::TwoFactor)}</h3>
+            {move || match step.get() {
+                Step::Enrolling(setup) => {
+                    let qr = qr_svg(&setup.otpauth_url);
+                    view! {
+                        <p>{i18n.t(Msg::ScanQrCode)}</p>
+                        {match qr {
+                            Ok(svg) => view! { <div class="qr-code" inner_html=svg></div> }.into_any(),
+                            Err(e) => view! { <p class="error-message">{e}</p> }.into_any(),
+                        }}
+                        <p class="two-factor-secret">{i18n.t(Msg::CantScan)}" "<code>{group_secret(&setup.secret)}</code></p>
+                        <form on:submit=on_enable>
+                            <div class="form-group">
+                                <label>{move || i18n.t(Msg::AuthCode)}</label>
+                                <TextInput field=enable_code kind="text" autocomplete="one-time-code" attr:inputmode="numeric" />
+                                <FieldError field=enable_code />
+                            </div>
+                            <button type="submit" class="submit-button" disabled=move || enable_form.pending()>
+                                {move || if enable_form.pending() { i18n.t(Msg::Verifying) } else { i18n.t(Msg::Verify) }}
+                            </button>
+                            <button type="button" class="cancel-button" on:click=move |_| step.set(Step::Idle)>
+                                {move || i18n.t(Msg::Cancel)}
+                            </button>
+                        </form>
+                    }.into_any()
+                }
+                Step::RecoveryCodes(codes) => {
+                    let download: String = js_sys::encode_uri_component(&codes.join("\n")).into();
+                    view! {
+                        <p>{i18n.t(Msg::RecoveryCodesHint)}</p>
+                        <ul class="recovery-codes">
+                            {codes.into_iter().map(|code| view! { <li><code>{code}</code></li> }).collect_view()}
+                        </ul>
+                        <a download="fuelfinder-recovery-codes.txt" href=format!("data:text/plain;charset=utf-8,{download}")>
+                            {i18n.t(Msg::DownloadCodes)}
+                        </a>
+                        <button class="submit-button" on:click=move |_| step.set(Step::Idle)>
+                            {i18n.t(Msg::SavedCodes)}
+                        </button>
+                    }.into_any()
+                }
+                Step::Idle if enabled.get() => view! {
+                    <p class="success-message">{i18n.t(Msg::TwoFactorOn)}</p>
+                    <form on:submit=on_manage>
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::EnterCodeToChange)}</label>
+                            <TextInput field=manage_code kind="text" autocomplete="one-time-code" attr:inputmode="numeric" />
+                            <FieldError field=manage_code />
+                        </div>
+                        <button
+                            type="submit"
+                            class="edit-button"
+                            disabled=move || manage_form.pending()
+                            on:click=move |_| change.set(Change::NewRecoveryCodes)
+                        >
+                            {move || i18n.t(Msg::NewRecoveryCodes)}
+                        </button>
+                        <button
+                            type="submit"
+                            class="cancel-button"
+                            disabled=move || manage_form.pending()
+                            on:click=move |_| change.set(Change::Disable)
+                        >
+                            {move || i18n.t(Msg::DisableTwoFactor)}
+                        </button>
+                    </form>
+                }.into_any(),
+                Step::Idle => view! {
+                    <p>{i18n.t(Msg::TwoFactorOff)}</p>
+                    <button
+                        class="submit-button"
+                        disabled=move || setup_action.pending().get()
+                        on:click=move |_| { setup_action.dispatch(()); }
+                    >
+                        {move || if setup_action.pending().get() { i18n.t(Msg::Sending) } else { i18n.t(Msg::SetUpTwoFactor) }}
+                    </button>
+                }.into_any(),
+            }}
+            {move || error().map(|err| view! { <small class="error-message">{err}</small> })}
+        </section>
+    }
+}
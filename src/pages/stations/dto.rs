@@ -1,9 +1,14 @@
-use gloo_net::http::Request;
+use gloo_net::http::{Method, Request, Response};
 use serde::{Deserialize, Serialize};
-use leptos::prelude::*;
 
-use crate::{pages::fetch_nearest_stations_dto::Station, utils::base_url};
+use crate::i18n::{I18n, Msg};
+use crate::pages::fetch_nearest_stations_dto::{Brand, Station};
+use crate::utils::api::{retry_after_secs, send_authed};
 use crate::utils::base_url::BaseUrl;
+use crate::utils::image_resize::ImageKind;
+use crate::utils::money::Money;
+use crate::utils::session::{Permission, SessionContext};
+use crate::utils::validate_boundary::AreaError;
 
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -14,6 +19,52 @@ pub struct RegisterFormData {
     pub phone: String,
     pub password: String,
     pub code: String,
+    pub brand: Brand,
+    pub commodities: Vec<InitialCommodity>,
+    /// Already resized, as JPEG `data:` URLs
+    pub photo: Option<String>,
+    pub logo: Option<String>,
+}
+
+/// The products a station can list; the API names them in lowercase
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommodityKind {
+    Pms,
+    Ago,
+    Dpk,
+    Lpg,
+}
+
+impl CommodityKind {
+    pub const ALL: [CommodityKind; 4] = [CommodityKind::Pms, CommodityKind::Ago, CommodityKind::Dpk, CommodityKind::Lpg];
+
+    /// Same as the wire name
+    pub fn code(self) -> &'static str {
+        match self {
+            CommodityKind::Pms => "pms",
+            CommodityKind::Ago => "ago",
+            CommodityKind::Dpk => "dpk",
+            CommodityKind::Lpg => "lpg",
+        }
+    }
+
+    /// Trade name with the everyday one, e.g. "PMS (petrol)"
+    pub fn label(self) -> Msg {
+        match self {
+            CommodityKind::Pms => Msg::CommodityPms,
+            CommodityKind::Ago => Msg::CommodityAgo,
+            CommodityKind::Dpk => Msg::CommodityDpk,
+            CommodityKind::Lpg => Msg::CommodityLpg,
+        }
+    }
+}
+
+/// A product the station is created with, listed as available at `price`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InitialCommodity {
+    pub name: CommodityKind,
+    pub price: Money,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -25,11 +76,182 @@ pub struct LoginFormData {
 #[derive(Serialize, Deserialize, Clone)]
 pub struct LoginResponse {
     pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+/// What `/auth/signin` answers: tokens straight away, or a challenge when the
+/// account has two-factor authentication turned on.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum SigninOutcome {
+    Tokens(LoginResponse),
+    TwoFactorRequired { mfa_token: String },
+}
+
+/// Used when a 429 doesn't say how long to wait
+const DEFAULT_RETRY_SECS: u64 = 60;
+
+/// Why a login was refused, so `Signin` can say more than a status code
+#[derive(Clone, Debug, PartialEq)]
+pub enum SigninError {
+    InvalidCredentials,
+    InvalidCode,
+    /// Too many attempts from this client
+    RateLimited { retry_after_secs: u64 },
+    /// Too many failed logins on the account; `None` when the API doesn't say for how long
+    Locked { retry_after_secs: Option<u64> },
+    Suspended,
+    Other(String),
+}
+
+impl SigninError {
+    /// `None` for `Other`, whose text comes from elsewhere
+    pub fn msg(&self) -> Option<Msg> {
+        match self {
+            SigninError::InvalidCredentials => Some(Msg::InvalidCredentials),
+            SigninError::InvalidCode => Some(Msg::InvalidCode),
+            SigninError::RateLimited { .. } => Some(Msg::TooManyAttempts),
+            SigninError::Locked { .. } => Some(Msg::AccountLocked),
+            SigninError::Suspended => Some(Msg::AccountSuspended),
+            SigninError::Other(_) => None,
+        }
+    }
+
+    /// How long the login button should stay disabled
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            SigninError::RateLimited { retry_after_secs } => Some(*retry_after_secs),
+            SigninError::Locked { retry_after_secs } => *retry_after_secs,
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for SigninError {
+    fn from(message: String) -> Self {
+        SigninError::Other(message)
+    }
 }
 
-pub async fn register_station(payload: RegisterFormData, lat: f64, lon:f64) -> Result<Station, String> {
-    let BASE_URL = BaseUrl::get_base_url();
-    let url = format!("{BASE_URL}/api/v1/auth/signup"); // Added "stations" to match typical API
+/// A failed request, with a translated message when the UI has one for it
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApiError {
+    Known(Msg),
+    Area(AreaError),
+    Other(String),
+}
+
+impl ApiError {
+    /// Reactive; what to show the user
+    pub fn text(&self, i18n: I18n) -> String {
+        match self {
+            ApiError::Known(msg) => i18n.t(*msg).to_string(),
+            ApiError::Area(e) => e.describe(i18n.t(e.msg())),
+            ApiError::Other(message) => format!("{}{}", i18n.t(Msg::ErrorOccurred), message),
+        }
+    }
+}
+
+impl From<AreaError> for ApiError {
+    fn from(e: AreaError) -> Self {
+        ApiError::Area(e)
+    }
+}
+
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        ApiError::Other(message)
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct AuthErrorBody {
+    #[serde(default)]
+    code: Option<String>,
+    /// Same as `Retry-After`, for when the header isn't exposed to us
+    #[serde(default)]
+    retry_after: Option<u64>,
+}
+
+/// `unauthorized` is what a 401 means for this endpoint: bad password or bad code
+async fn signin_error(resp: Response, unauthorized: SigninError) -> SigninError {
+    let header_secs = retry_after_secs(&resp);
+    let status = resp.status();
+    let body: AuthErrorBody = resp.json().await.unwrap_or_default();
+    let retry_after = header_secs.or(body.retry_after);
+
+    match (status, body.code.as_deref()) {
+        (_, Some("account_suspended")) | (403, None) => SigninError::Suspended,
+        (_, Some("account_locked")) | (423, _) => SigninError::Locked { retry_after_secs: retry_after },
+        (429, _) => SigninError::RateLimited { retry_after_secs: retry_after.unwrap_or(DEFAULT_RETRY_SECS) },
+        (401, _) => unauthorized,
+        _ => SigninError::Other(format!("Server error: {}", status)),
+    }
+}
+
+/// The second login step. `code` is either the 6-digit TOTP or a recovery code.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorChallenge {
+    pub mfa_token: String,
+    pub code: String,
+    pub recovery: bool,
+}
+
+/// A fresh TOTP secret, not active until confirmed with a code from it
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorSetup {
+    /// Base32, for typing into the app by hand
+    pub secret: String,
+    /// `otpauth://totp/...`, what the QR code encodes
+    pub otpauth_url: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RecoveryCodes {
+    pub recovery_codes: Vec<String>,
+}
+
+/// A device signed in to the station, as listed by `/api/v1/auth/sessions`
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DeviceSession {
+    pub id: String,
+    #[serde(default)]
+    pub device: Option<String>,
+    #[serde(default)]
+    pub ip_address: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub last_active_at: Option<String>,
+    /// The session this browser is using
+    #[serde(default)]
+    pub current: bool,
+}
+
+/// An attendant account under the station, as listed by `/api/v1/stations/staff`
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StaffMember {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
+    /// Invited but hasn't set a password yet
+    #[serde(default)]
+    pub invite_pending: bool,
+    pub created_at: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StaffInvite {
+    pub name: String,
+    pub email: String,
+    pub permissions: Vec<Permission>,
+}
+
+pub async fn register_station(payload: RegisterFormData, lat: f64, lon:f64) -> Result<Station, ApiError> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/signup"); // Added "stations" to match typical API
     let payload = serde_json::json!({
                 "name": payload.name,
                 "address": payload.address,
@@ -37,6 +259,10 @@ pub async fn register_station(payload: RegisterFormData, lat: f64, lon:f64) -> R
                 "phone": payload.phone,
                 "password": payload.password,
                 "code": payload.code,
+                "brand": payload.brand,
+                "commodities": payload.commodities,
+                "photo": payload.photo,
+                "logo": payload.logo,
                 "latitude": lat,
                 "longitude": lon
             });
@@ -51,20 +277,23 @@ pub async fn register_station(payload: RegisterFormData, lat: f64, lon:f64) -> R
         Ok(resp) => {
             if resp.ok() {
                 // If 200-299 status code
-                resp.json::<Station>().await.map_err(|e| format!("Parsing error: {}", e))
+                resp.json::<Station>().await.map_err(|e| format!("Parsing error: {}", e).into())
+            } else if resp.status() == 413 {
+                // The photo and logo ride along as base64, which a proxy may cap
+                Err(ApiError::Known(Msg::SignupTooLarge))
             } else {
                 // If 4xx or 5xx status code
-                Err(format!("Server error: {}", resp.status()))
+                Err(format!("Server error: {}", resp.status()).into())
             }
         }
         // If network failed entirely
-        Err(e) => Err(format!("Network error: {}", e)),
+        Err(e) => Err(format!("Network error: {}", e).into()),
     }
 }
 
-pub async fn login_station(payload: LoginFormData) -> Result<LoginResponse, String> {
-    let BASE_URL = BaseUrl::get_base_url();
-    let url = format!("{BASE_URL}/api/v1/auth/signin"); // Added "stations" to match typical API
+pub async fn login_station(payload: LoginFormData) -> Result<SigninOutcome, SigninError> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/signin"); // Added "stations" to match typical API
     let request = Request::post(url.as_str())
         .header("Content-Type", "application/json")
         .json(&payload) // This serializes the JSON and sends it
@@ -76,14 +305,359 @@ pub async fn login_station(payload: LoginFormData) -> Result<LoginResponse, Stri
         Ok(resp) => {
             if resp.ok() {
                 // If 200-299 status code
-                let response:LoginResponse = resp.json().await.map_err(|e| format!("Error while parsing, {}",e.to_string()))?;
+                let response:SigninOutcome = resp.json().await.map_err(|e| format!("Error while parsing, {}",e))?;
                 Ok(response)
             } else {
                 // If 4xx or 5xx status code
-                Err(format!("Server error: {}", resp.status()))
+                Err(signin_error(resp, SigninError::InvalidCredentials).await)
             }
         }
         // If network failed entirely
+        Err(e) => Err(format!("Network error: {}", e).into()),
+    }
+}
+
+/// Revokes this device's refresh token on the server
+pub async fn logout_station(session: SessionContext) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/logout");
+    let refresh_token = session.get_untracked().and_then(|s| s.refresh_token);
+    let payload = serde_json::json!({ "refresh_token": refresh_token });
+    let resp = send_authed(session, Method::POST, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+pub async fn fetch_sessions(session: SessionContext) -> Result<Vec<DeviceSession>, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/sessions");
+    let resp = send_authed(session, Method::GET, &url, None).await?;
+
+    if resp.ok() {
+        resp.json::<Vec<DeviceSession>>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+pub async fn revoke_session(session: SessionContext, id: &str) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/sessions/{id}");
+    let resp = send_authed(session, Method::DELETE, &url, None).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+pub async fn request_password_reset(email: String) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/forgot-password");
+    let payload = serde_json::json!({ "email": email });
+    let request = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await;
+
+    match request {
+        Ok(resp) => {
+            if resp.ok() {
+                Ok(())
+            } else {
+                Err(format!("Server error: {}", resp.status()))
+            }
+        }
+        Err(e) => Err(format!("Network error: {}", e)),
+    }
+}
+
+pub async fn reset_password(token: String, password: String) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/reset-password");
+    let payload = serde_json::json!({ "token": token, "password": password });
+    let request = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await;
+
+    match request {
+        Ok(resp) => {
+            if resp.ok() {
+                Ok(())
+            } else if resp.status() == 400 || resp.status() == 410 {
+                // Used, expired or tampered with
+                Err("This reset link is no longer valid, please request a new one".to_string())
+            } else {
+                Err(format!("Server error: {}", resp.status()))
+            }
+        }
         Err(e) => Err(format!("Network error: {}", e)),
     }
-}
\ No newline at end of file
+}
+
+pub async fn verify_email(token: String) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/verify-email");
+    let payload = serde_json::json!({ "token": token });
+    let request = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await;
+
+    match request {
+        Ok(resp) => {
+            if resp.ok() {
+                Ok(())
+            } else if resp.status() == 400 || resp.status() == 410 {
+                Err("This verification link is invalid or has expired. Log in to request a new one.".to_string())
+            } else {
+                Err(format!("Server error: {}", resp.status()))
+            }
+        }
+        Err(e) => Err(format!("Network error: {}", e)),
+    }
+}
+
+pub async fn resend_verification(email: String) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/resend-verification");
+    let payload = serde_json::json!({ "email": email });
+    let request = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await;
+
+    match request {
+        Ok(resp) => {
+            if resp.ok() {
+                Ok(())
+            } else {
+                Err(format!("Server error: {}", resp.status()))
+            }
+        }
+        Err(e) => Err(format!("Network error: {}", e)),
+    }
+}
+pub async fn fetch_staff(session: SessionContext) -> Result<Vec<StaffMember>, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/stations/staff");
+    let resp = send_authed(session, Method::GET, &url, None).await?;
+
+    if resp.ok() {
+        resp.json::<Vec<StaffMember>>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+/// Emails the attendant a link to `/accept-invite` where they choose a password
+pub async fn invite_staff(session: SessionContext, invite: StaffInvite) -> Result<StaffMember, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/stations/staff");
+    let payload = serde_json::to_value(&invite).map_err(|e| e.to_string())?;
+    let resp = send_authed(session, Method::POST, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        resp.json::<StaffMember>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else if resp.status() == 409 {
+        Err("That email already belongs to an account".to_string())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+pub async fn update_staff_permissions(session: SessionContext, id: &str, permissions: Vec<Permission>) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/stations/staff/{id}");
+    let payload = serde_json::json!({ "permissions": permissions });
+    let resp = send_authed(session, Method::PATCH, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+/// Deletes the account and signs it out everywhere
+pub async fn remove_staff(session: SessionContext, id: &str) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/stations/staff/{id}");
+    let resp = send_authed(session, Method::DELETE, &url, None).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+/// One row of a bulk price update
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PriceUpdate {
+    pub id: String,
+    pub price: Money,
+    pub is_available: bool,
+}
+
+/// Saves several commodities in one request. The API applies all of them or
+/// none, so a rejected batch leaves every price as it was.
+pub async fn update_prices(session: SessionContext, updates: Vec<PriceUpdate>) -> Result<(), ApiError> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/commodities");
+    let payload = serde_json::json!({ "commodities": updates });
+    let resp = send_authed(session, Method::PATCH, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else if resp.status() == 403 {
+        Err(ApiError::Known(Msg::PricesNotAllowed))
+    } else {
+        Err(format!("Server error: {}", resp.status()).into())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct UploadedImage {
+    url: String,
+}
+
+/// Replaces the station's photo or logo with a resized JPEG `data:` URL and
+/// returns where it's now served from
+pub async fn upload_station_image(session: SessionContext, kind: ImageKind, data_url: String) -> Result<String, ApiError> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/stations/images/{}", kind.path());
+    let payload = serde_json::json!({ "image": data_url });
+    let resp = send_authed(session, Method::PUT, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        let uploaded = resp.json::<UploadedImage>().await.map_err(|e| format!("Parsing error: {}", e))?;
+        Ok(uploaded.url)
+    } else if resp.status() == 413 {
+        Err(ApiError::Known(Msg::ImageTooLarge))
+    } else {
+        Err(format!("Server error: {}", resp.status()).into())
+    }
+}
+
+pub async fn accept_staff_invite(token: String, password: String) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/accept-invite");
+    let payload = serde_json::json!({ "token": token, "password": password });
+    let request = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await;
+
+    match request {
+        Ok(resp) => {
+            if resp.ok() {
+                Ok(())
+            } else if resp.status() == 400 || resp.status() == 410 {
+                Err("This invite link is no longer valid, ask the station owner to send a new one".to_string())
+            } else {
+                Err(format!("Server error: {}", resp.status()))
+            }
+        }
+        Err(e) => Err(format!("Network error: {}", e)),
+    }
+}
+
+pub async fn verify_two_factor(challenge: TwoFactorChallenge) -> Result<LoginResponse, SigninError> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/2fa/verify");
+    let payload = if challenge.recovery {
+        serde_json::json!({ "mfa_token": challenge.mfa_token, "recovery_code": challenge.code })
+    } else {
+        serde_json::json!({ "mfa_token": challenge.mfa_token, "code": challenge.code })
+    };
+    let request = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await;
+
+    match request {
+        Ok(resp) => {
+            if resp.ok() {
+                Ok(resp.json::<LoginResponse>().await.map_err(|e| format!("Parsing error: {}", e))?)
+            } else if resp.status() == 400 {
+                Err(SigninError::InvalidCode)
+            } else if resp.status() == 410 {
+                // The mfa_token only lives a few minutes
+                Err("This login attempt has expired, please log in again".to_string().into())
+            } else {
+                Err(signin_error(resp, SigninError::InvalidCode).await)
+            }
+        }
+        Err(e) => Err(format!("Network error: {}", e).into()),
+    }
+}
+
+pub async fn start_two_factor_setup(session: SessionContext) -> Result<TwoFactorSetup, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/2fa/setup");
+    let resp = send_authed(session, Method::POST, &url, None).await?;
+
+    if resp.ok() {
+        resp.json::<TwoFactorSetup>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+/// Turns 2FA on once the app shows a matching code; the recovery codes come back only here
+pub async fn enable_two_factor(session: SessionContext, code: String) -> Result<RecoveryCodes, String> {
+    two_factor_request(session, "enable", code).await
+}
+
+pub async fn regenerate_recovery_codes(session: SessionContext, code: String) -> Result<RecoveryCodes, String> {
+    two_factor_request(session, "recovery-codes", code).await
+}
+
+pub async fn disable_two_factor(session: SessionContext, code: String) -> Result<(), String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/2fa/disable");
+    let payload = serde_json::json!({ "code": code });
+    let resp = send_authed(session, Method::POST, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        Ok(())
+    } else if resp.status() == 400 || resp.status() == 422 {
+        Err("That code didn't work, please try again".to_string())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+async fn two_factor_request(session: SessionContext, path: &str, code: String) -> Result<RecoveryCodes, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/2fa/{path}");
+    let payload = serde_json::json!({ "code": code });
+    let resp = send_authed(session, Method::POST, &url, Some(payload)).await?;
+
+    if resp.ok() {
+        resp.json::<RecoveryCodes>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else if resp.status() == 400 || resp.status() == 422 {
+        Err("That code didn't work, please try again".to_string())
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::pages::stations::dto::request_password_reset;
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::components::form_field::{FieldError, TextInput};
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::form::{Form, check};
+use crate::utils::validation::validate_email;
+
+#[component]
+pub fn ForgotPassword() -> impl IntoView {
+    let i18n = use_i18n();
+
+    let request_action = Action::new_local(move |email: &String| {
+        let email = email.clone();
+        async move { request_password_reset(email).await }
+    });
+
+    let form = Form::new(request_action);
+    let email = form.field("email", check(validate_email));
+    let on_submit = form.on_submit(move || email.parse());
+
+    view! {
+        <div class="form-container">
+            <h2>{move || i18n.t(Msg::ForgotPasswordTitle)}</h2>
+
+            <Show
+                when=move || matches!(request_action.value().get(), Some(Ok(())))
+                fallback=move || view! {
+                    <p>{move || i18n.t(Msg::ForgotPasswordHint)}</p>
+                    <form on:submit=on_submit>
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::Email)}</label>
+                            <TextInput field=email kind="email" autocomplete="email" />
+                            <FieldError field=email />
+                        </div>
+
+                        <button type="submit" class="submit-button" disabled=move || form.pending()>
+                            {move || if form.pending() { i18n.t(Msg::Sending) } else { i18n.t(Msg::SendResetLink) }}
+                        </button>
+                    </form>
+                }
+            >
+                // Same message whether or not the email exists, so the form can't be used to probe accounts
+                <p class="success-message">{move || i18n.t(Msg::ResetLinkSent)}</p>
+            </Show>
+
+            <p><A href="/signin">{move || i18n.t(Msg::BackToLogin)}</A></p>
+            {move || request_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{i18n.t(Msg::ErrorOccurred)}{err}</small>
+            })}
+        </div>
+    }
+}
@@ -1,4 +1,8 @@
 pub mod signin;
 pub mod signup;
 pub mod dto;
-pub mod dashboard;
\ No newline at end of file
+pub mod dashboard;
+pub mod forgot_password;
+pub mod reset_password;
+pub mod verify_email;
+pub mod accept_invite;
\ No newline at end of file
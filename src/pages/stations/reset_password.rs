@@ -0,0 +1,95 @@
+use crate::pages::stations::dto::reset_password;
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_query_map};
+
+use crate::components::form_field::{FieldError, TextInput};
+use crate::components::password_strength::PasswordStrengthMeter;
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::form::{Form, check};
+use crate::utils::validation::validate_new_password;
+
+#[component]
+pub fn ResetPassword() -> impl IntoView {
+    let i18n = use_i18n();
+    let query = use_query_map();
+    let token = move || query.read().get("token").filter(|t| !t.is_empty());
+    let show_password = RwSignal::new(false);
+
+    let reset_action = Action::new_local(move |(token, password): &(String, String)| {
+        let token = token.clone();
+        let password = password.clone();
+        async move { reset_password(token, password).await }
+    });
+
+    let form = Form::new(reset_action);
+    let password = form.field("password", check(|v: &str| validate_new_password(v).map(|_| v.to_string())));
+    // Reads the password reactively so the mismatch clears as soon as either box is fixed
+    let confirm_password = form.field("confirm_password", move |v: &str| {
+        if v == password.value() { Ok(()) } else { Err(Msg::PasswordsDoNotMatch) }
+    });
+    let on_submit = form.on_submit(move || {
+        confirm_password.parse()?;
+        let token = query.read_untracked().get("token").ok_or(Msg::ResetTokenMissing)?;
+        Ok((token, password.parse()?))
+    });
+
+    view! {
+        <div class="form-container">
+            <h2>{move || i18n.t(Msg::ResetPasswordTitle)}</h2>
+
+            {move || if token().is_none() {
+                view! { <p class="error-message">{i18n.t(Msg::ResetTokenMissing)}</p> }.into_any()
+            } else if matches!(reset_action.value().get(), Some(Ok(()))) {
+                view! { <p class="success-message">{i18n.t(Msg::PasswordResetDone)}</p> }.into_any()
+            } else {
+                view! {
+                    <form on:submit=on_submit>
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::NewPassword)}</label>
+                            <div class="password-wrapper">
+                                <TextInput
+                                    field=password
+                                    kind=Signal::derive(move || if show_password.get() { "text" } else { "password" })
+                                    autocomplete="new-password"
+                                    attr:style="width: 100%; padding-right: 40px;"
+                                />
+                                <button
+                                    type="button"
+                                    class="password-toggle"
+                                    on:click=move |_| show_password.update(|v| *v = !*v)
+                                >
+                                    {move || if show_password.get() { i18n.t(Msg::HidePassword) } else { i18n.t(Msg::ShowPassword) }}
+                                </button>
+                            </div>
+                            <PasswordStrengthMeter password=Signal::derive(move || password.value()) />
+                            <FieldError field=password />
+                        </div>
+
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::ConfirmPassword)}</label>
+                            <TextInput
+                                field=confirm_password
+                                kind=Signal::derive(move || if show_password.get() { "text" } else { "password" })
+                                autocomplete="new-password"
+                            />
+                            <FieldError field=confirm_password />
+                        </div>
+
+                        <button type="submit" class="submit-button" disabled=move || form.pending()>
+                            {move || if form.pending() { i18n.t(Msg::Saving) } else { i18n.t(Msg::ResetPassword) }}
+                        </button>
+                    </form>
+                }.into_any()
+            }}
+
+            <p>
+                <A href="/signin">{move || i18n.t(Msg::BackToLogin)}</A>
+                " · "
+                <A href="/forgot-password">{move || i18n.t(Msg::RequestNewLink)}</A>
+            </p>
+            {move || reset_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{i18n.t(Msg::ErrorOccurred)}{err}</small>
+            })}
+        </div>
+    }
+}
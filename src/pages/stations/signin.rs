@@ -1,103 +1,228 @@
-use crate::{pages::stations::dto::{LoginFormData, login_station}};
-use leptos::{logging, prelude::*};
-use wasm_bindgen::JsCast;
-use leptos_router::{components::{A, Form}, hooks::use_navigate};
+use crate::{pages::stations::dto::{LoginFormData, LoginResponse, SigninError, SigninOutcome, TwoFactorChallenge, login_station, verify_two_factor}};
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::{use_navigate, use_query_map}};
+
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::app_config::AppConfig;
+use crate::utils::countdown::{Countdown, use_countdown};
+use crate::utils::protect_route::safe_next;
+use crate::utils::session::{SessionContext, use_session};
+use crate::components::form_field::{FieldError, TextInput};
+use crate::utils::form::{Form, check};
+use crate::utils::validation::{ValidationError, validate_email, validate_otp, validate_recovery_code};
+
+/// Stores the tokens and works out where to go: back to the page that sent us
+/// here, if any; admins otherwise start in /admin
+fn finish_signin(session: SessionContext, tokens: &LoginResponse, next: Option<String>) -> Result<String, String> {
+    let signed_in = session.sign_in(&tokens.access_token, tokens.refresh_token.as_deref())?;
+    Ok(safe_next(next, signed_in.home_path()))
+}
+
+/// Keeps the submit button disabled for as long as the API asked us to wait
+fn use_lockout<I: Send + Sync + 'static>(action: Action<I, Result<(), SigninError>>) -> Countdown {
+    let lockout = use_countdown();
+    Effect::new(move |_| {
+        let wait = action.value().with(|v| match v {
+            Some(Err(e)) => e.retry_after_secs(),
+            _ => None,
+        });
+        if let Some(secs) = wait {
+            lockout.start(secs);
+        }
+    });
+    lockout
+}
+
+/// What went wrong, plus the way out where there is one
+#[component]
+fn SigninErrorMessage(error: SigninError) -> impl IntoView {
+    let i18n = use_i18n();
+    let support = AppConfig::current().support.clone();
+    let msg = error.msg();
+    let other = match &error {
+        SigninError::Other(message) => message.clone(),
+        _ => String::new(),
+    };
+
+    view! {
+        <small class="error-message">
+            {move || msg.map(|m| i18n.t(m).to_string()).unwrap_or_else(|| other.clone())}
+        </small>
+        {matches!(error, SigninError::Locked { .. }).then(|| view! {
+            <A href="/forgot-password" attr:class="forgot-password-link">{move || i18n.t(Msg::ForgotPassword)}</A>
+        })}
+        {(error == SigninError::Suspended).then(|| support.email.map(|email| view! {
+            <small><a href=format!("mailto:{email}")>{email.clone()}</a></small>
+        }))}
+    }
+}
 
 #[component]
 pub fn Signin() -> impl IntoView {
-    // 1. Reactive state for errors and server messages
-    let server_error = RwSignal::new(None::<String>);
-    let validation_errors = RwSignal::new(std::collections::HashMap::<String, String>::new());
+    let i18n = use_i18n();
     let navigate = use_navigate();
+    let session = use_session();
+    let query = use_query_map();
     let show_password = RwSignal::new(false);
+    // Set when the account has 2FA on and the API wants a code before issuing tokens
+    let mfa_token = RwSignal::new(None::<String>);
     
-    // 2. The Registration Action
-    // We use Action::new_local because we are doing client-side GPS work first
+    // 2. The Login Action
     let login_action = Action::new_local(move |data: &LoginFormData| {
         let payload = data.clone();
         let navigate = navigate.clone();
         
         async move {
-            let data = login_station(payload).await?;
-            let window = web_sys::window().unwrap();
-            let storage = window.local_storage().unwrap().unwrap();
-            storage.set_item("accessToken", &data.access_token).unwrap();
-            navigate("/station", Default::default());
-            
-            Ok("Success".to_string())
+            match login_station(payload).await? {
+                SigninOutcome::Tokens(tokens) => {
+                    let next = finish_signin(session, &tokens, query.read_untracked().get("next"))?;
+                    navigate(&next, Default::default());
+                }
+                SigninOutcome::TwoFactorRequired { mfa_token: token } => mfa_token.set(Some(token)),
+            }
+            Ok::<(), SigninError>(())
         }
     });
+    let lockout = use_lockout(login_action);
 
-    // 3. Form Validation Logic
-    let on_submit = move |ev: leptos::ev::SubmitEvent| {
-        ev.prevent_default();
-        
-        // Extract data from the form element
-        let form_data = web_sys::FormData::new_with_form(&ev.target().unwrap().unchecked_into())
-            .expect("Failed to get form data");
-        
-        let email = form_data.get("email").as_string().unwrap_or_default();
-        let password = form_data.get("password").as_string().unwrap_or_default();
-        
-        let mut errors = std::collections::HashMap::new();
-
-        // Validations
-        if email.is_empty() { errors.insert("email".into(), "Email is required".into()); }
-        if password.is_empty() { errors.insert("password".into(), "Password is required".into()); }
-        
-        if errors.is_empty() {
-            validation_errors.set(errors);
-            let data = LoginFormData {
-                email,
-                password,
-            };
-            login_action.dispatch(data);
-        } else {
-            validation_errors.set(errors);
-        }
-    };
+    // 3. Fields; the password's strength was checked when it was chosen
+    let form = Form::new(login_action);
+    let email = form.field("email", check(validate_email));
+    let password = form.field("password", |v: &str| {
+        if v.is_empty() { Err(ValidationError::PasswordRequired.msg()) } else { Ok(v.to_string()) }
+    });
+    let on_submit = form.on_submit(move || Ok(LoginFormData { email: email.parse()?, password: password.parse()? }));
 
     view! {
         <div class="form-container">
-            <form on:submit=on_submit>
-                <div class="form-group">
-                    <label>"Email"</label>
-                    <input type="email" name="email" autocomplete="email"/>
-                    {move || validation_errors.get().get("email").map(|m| view! { <small class="error-message">{m.clone()}</small> })}
-                </div>
-
-                <div class="form-group">
-                    <label>"Password"</label>
-                    <div class="password-wrapper">
-                        // 2. Dynamic type based on show_password signal
-                        <input 
-                            type=move || if show_password.get() { "text" } else { "password" } 
-                            name="password" 
-                            style="width: 100%; padding-right: 40px;"
-                        />
-                        // 3. Eye Toggle Button
-                        <button 
-                            type="button" 
-                            class="password-toggle"
-                            on:click=move |_| show_password.update(|v| *v = !*v)
-                        >
-                            {move || if show_password.get() { "hide" } else { "show" }}
-                        </button>
-                    </div>
-                    {move || validation_errors.get().get("password").map(|m| view! { <small class="error-message">{m.clone()}</small> })}
-                </div>
+            {move || match mfa_token.get() {
+                Some(token) => view! {
+                    <TwoFactorStep mfa_token=token on_back=move |_| mfa_token.set(None) />
+                }.into_any(),
+                None => view! {
+                    <form on:submit=on_submit>
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::Email)}</label>
+                            <TextInput field=email kind="email" autocomplete="email" />
+                            <FieldError field=email />
+                        </div>
 
-                <button type="submit" class="submit-button" disabled=move || login_action.pending().get()>
-                    {move || if login_action.pending().get() { "..." } else { "Login" }}
-                </button>
-            </form>
+                        <div class="form-group">
+                            <label>{move || i18n.t(Msg::Password)}</label>
+                            <div class="password-wrapper">
+                                // 2. Dynamic type based on show_password signal
+                                <TextInput
+                                    field=password
+                                    kind=Signal::derive(move || if show_password.get() { "text" } else { "password" })
+                                    autocomplete="current-password"
+                                    attr:style="width: 100%; padding-right: 40px;"
+                                />
+                                // 3. Eye Toggle Button
+                                <button 
+                                    type="button" 
+                                    class="password-toggle"
+                                    on:click=move |_| show_password.update(|v| *v = !*v)
+                                >
+                                    {move || if show_password.get() { i18n.t(Msg::HidePassword) } else { i18n.t(Msg::ShowPassword) }}
+                                </button>
+                            </div>
+                            <FieldError field=password />
+                            <A href="/forgot-password" attr:class="forgot-password-link">{move || i18n.t(Msg::ForgotPassword)}</A>
+                        </div>
 
-            <p>"Do not have an account? " <A href="/signup">"Register"</A></p>
-            // Server Error Display
-            {move || login_action.value().get().and_then(|res| res.err()).map(|err: String| view! {
-                <small class="error-message">{err}</small>
-            })}
+                        <button type="submit" class="submit-button" disabled=move || form.pending() || lockout.is_running()>
+                            {move || if form.pending() {
+                                "...".to_string()
+                            } else if lockout.is_running() {
+                                i18n.t(Msg::TryAgainIn).replace("{time}", &lockout.label())
+                            } else {
+                                i18n.t(Msg::Login).to_string()
+                            }}
+                        </button>
+                    </form>
+
+                    <p>{move || i18n.t(Msg::NoAccount)} <A href="/signup">{move || i18n.t(Msg::Register)}</A></p>
+                    // Server Error Display
+                    {move || login_action.value().get().and_then(|res| res.err()).map(|error| view! {
+                        <SigninErrorMessage error=error />
+                    })}
+                }.into_any(),
+            }}
         </div>
     }
         
+}
+
+/// Second login step for accounts with 2FA: a code from the authenticator app,
+/// or one of the recovery codes if the phone is gone.
+#[component]
+fn TwoFactorStep(mfa_token: String, #[prop(into)] on_back: Callback<()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = use_navigate();
+    let session = use_session();
+    let query = use_query_map();
+    let mfa_token = StoredValue::new(mfa_token);
+    let use_recovery = RwSignal::new(false);
+
+    let verify_action = Action::new_local(move |challenge: &TwoFactorChallenge| {
+        let challenge = challenge.clone();
+        let navigate = navigate.clone();
+        async move {
+            let tokens = verify_two_factor(challenge).await?;
+            let next = finish_signin(session, &tokens, query.read_untracked().get("next"))?;
+            navigate(&next, Default::default());
+            Ok::<(), SigninError>(())
+        }
+    });
+    let lockout = use_lockout(verify_action);
+
+    let form = Form::new(verify_action);
+    let code = form.field("code", move |v: &str| {
+        let code = if use_recovery.get() { validate_recovery_code(v) } else { validate_otp(v) };
+        code.map_err(ValidationError::msg)
+    });
+    let on_submit = form.on_submit(move || Ok(TwoFactorChallenge {
+        mfa_token: mfa_token.get_value(),
+        code: code.parse()?,
+        recovery: use_recovery.get_untracked(),
+    }));
+
+    view! {
+        <form on:submit=on_submit>
+            <p>{move || if use_recovery.get() { i18n.t(Msg::RecoveryCodePrompt) } else { i18n.t(Msg::TwoFactorPrompt) }}</p>
+            <div class="form-group">
+                <label>{move || if use_recovery.get() { i18n.t(Msg::RecoveryCode) } else { i18n.t(Msg::AuthCode) }}</label>
+                <TextInput field=code autocomplete="one-time-code" />
+                <FieldError field=code />
+                <button
+                    type="button"
+                    class="link-button"
+                    on:click=move |_| {
+                        use_recovery.update(|r| *r = !*r);
+                        form.reset();
+                    }
+                >
+                    {move || if use_recovery.get() { i18n.t(Msg::UseAuthenticator) } else { i18n.t(Msg::UseRecoveryCode) }}
+                </button>
+            </div>
+
+            <button type="submit" class="submit-button" disabled=move || form.pending() || lockout.is_running()>
+                {move || if form.pending() {
+                    i18n.t(Msg::Verifying).to_string()
+                } else if lockout.is_running() {
+                    i18n.t(Msg::TryAgainIn).replace("{time}", &lockout.label())
+                } else {
+                    i18n.t(Msg::Verify).to_string()
+                }}
+            </button>
+        </form>
+        <p>
+            <button type="button" class="link-button" on:click=move |_| on_back.run(())>
+                {move || i18n.t(Msg::BackToLogin)}
+            </button>
+        </p>
+        {move || verify_action.value().get().and_then(|res| res.err()).map(|error| view! {
+            <SigninErrorMessage error=error />
+        })}
+    }
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::steps::Step;
+use crate::pages::stations::dto::CommodityKind;
+
+const DRAFT_KEY: &str = "signup_draft";
+
+/// What the owner has typed so far, kept in local storage so a failed GPS
+/// fix or a closed tab doesn't cost them the whole form. The password is
+/// left out on purpose, and so is the location, which has to be fresh.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SignupDraft {
+    pub step: Step,
+    pub name: String,
+    pub address: String,
+    pub code: String,
+    /// `Brand::code`, or empty before one is chosen
+    pub brand: String,
+    pub email: String,
+    pub phone: String,
+    pub commodities: Vec<DraftCommodity>,
+}
+
+/// A product row as left on the products step, price as typed
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DraftCommodity {
+    pub kind: CommodityKind,
+    pub selected: bool,
+    pub price: String,
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+impl SignupDraft {
+    /// The saved draft, unless there is none or it's unreadable
+    pub fn load() -> Option<Self> {
+        let json = storage()?.get_item(DRAFT_KEY).ok().flatten()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn save(&self) {
+        if let (Some(storage), Ok(json)) = (storage(), serde_json::to_string(self)) {
+            let _ = storage.set_item(DRAFT_KEY, &json);
+        }
+    }
+
+    pub fn clear() {
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(DRAFT_KEY);
+        }
+    }
+
+    /// Nothing worth resuming
+    pub fn is_empty(&self) -> bool {
+        [&self.name, &self.address, &self.code, &self.brand, &self.email, &self.phone].iter().all(|v| v.is_empty())
+            && self.commodities.iter().all(|c| !c.selected && c.price.is_empty())
+    }
+}
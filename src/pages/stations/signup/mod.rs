@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub mod signup;
+pub mod draft;
+pub mod steps;
@@ -0,0 +1,472 @@
+use crate::pages::fetch_nearest_stations_dto::Brand;
+use crate::{pages::stations::dto::{ApiError, CommodityKind, InitialCommodity, RegisterFormData, register_station}, utils::{app_config::AppConfig, get_gps_location::{GpsFix, locate}, validate_boundary}};
+use crate::components::image_picker::ImagePicker;
+use crate::components::location_picker::LocationPicker;
+use leptos::{ev::SubmitEvent, logging, prelude::*};
+use leptos_router::{components::A, hooks::use_navigate};
+
+use super::draft::{DraftCommodity, SignupDraft};
+use super::steps::{Step, WizardProgress};
+use crate::components::form_field::{FieldError, TextInput};
+use crate::components::password_strength::PasswordStrengthMeter;
+use crate::i18n::{Msg, use_i18n};
+use crate::utils::form::{Field, Form, check, required};
+use crate::utils::image_resize::ImageKind;
+use crate::utils::money::{Money, MoneyError};
+use crate::utils::validation::{normalize_phone, validate_email, validate_new_password};
+
+/// Fixes rougher than this can't tell one forecourt from the next
+const MAX_FIX_ACCURACY_M: f64 = 50.0;
+/// Long enough to fill in the form, short enough that the owner is still on site
+const MAX_FIX_AGE_SECS: f64 = 15.0 * 60.0;
+
+/// The fix, if it's good enough to register a station at
+fn usable_fix(fix: Option<GpsFix>) -> Result<GpsFix, Msg> {
+    let fix = fix.ok_or(Msg::LocationRequired)?;
+    if fix.accuracy_m > MAX_FIX_ACCURACY_M {
+        return Err(Msg::LocationTooImprecise);
+    }
+    if fix.age_secs() > MAX_FIX_AGE_SECS {
+        return Err(Msg::LocationStale);
+    }
+    Ok(fix)
+}
+
+/// One row of the products step: sold or not, and at what price
+#[derive(Clone, Copy)]
+struct ProductRow {
+    kind: CommodityKind,
+    selected: RwSignal<bool>,
+    price: Field<Option<Money>>,
+}
+
+fn price_field(kind: CommodityKind) -> &'static str {
+    match kind {
+        CommodityKind::Pms => "price_pms",
+        CommodityKind::Ago => "price_ago",
+        CommodityKind::Dpk => "price_dpk",
+        CommodityKind::Lpg => "price_lpg",
+    }
+}
+
+#[component]
+pub fn Signup() -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = use_navigate();
+
+    // 1. Reactive state for password visibility
+    let show_password = RwSignal::new(false);
+
+    let draft = SignupDraft::load().filter(|d| !d.is_empty());
+    let resumed = RwSignal::new(draft.is_some());
+    let draft = draft.unwrap_or_default();
+    let step = RwSignal::new(draft.step);
+    // Problems with the current step that aren't tied to one field
+    let step_error = RwSignal::new(None::<Msg>);
+
+    let locate_action = Action::new_local(|_: &()| locate());
+    let located = locate_action.value();
+    let fix = Signal::derive(move || located.get().and_then(Result::ok));
+    let pin = RwSignal::new((0.0, 0.0));
+    Effect::new(move |_| {
+        if let Some(fix) = fix.get() {
+            pin.set((fix.latitude, fix.longitude));
+        }
+    });
+    // Locate when the owner reaches the location step, not before, so the
+    // fix is as fresh as it can be and is taken on site
+    Effect::new(move |_| {
+        if step.get() == Step::Location && located.get_untracked().is_none() && !locate_action.pending().get_untracked() {
+            locate_action.dispatch(());
+        }
+    });
+
+    let register_action = Action::new_local(move |(data, lat, lon): &(RegisterFormData, f64, f64)| {
+        let (data, lat, lon) = (data.clone(), *lat, *lon);
+        let navigate = navigate.clone();
+        let email = data.email.clone();
+
+        async move {
+            validate_boundary::validate_service_area(lat, lon, &AppConfig::current().enabled_regions)?;
+            let _station = register_station(data, lat, lon).await?;
+            logging::log!("Registering at: {}, {}", lat, lon);
+            SignupDraft::clear();
+            // Owners confirm their email before the first login
+            let query: String = js_sys::encode_uri_component(&email).into();
+            navigate(&format!("/check-email?email={query}"), Default::default());
+
+            Ok::<_, ApiError>("Success".to_string())
+        }
+    });
+
+    // A restored draft is where the fields start, so it doesn't count as a change
+    let form = Form::new(register_action);
+    let name = form.field_with_initial("name", draft.name, required(Msg::NameRequired));
+    let address = form.field_with_initial("address", draft.address, required(Msg::AddressRequired));
+    let email = form.field_with_initial("email", draft.email, check(validate_email));
+    // Sent to the API as +234XXXXXXXXXX whatever way it was typed
+    let phone = form.field_with_initial("phone", draft.phone, check(normalize_phone));
+    let password = form.field("password", check(|v: &str| validate_new_password(v).map(|_| v.to_string())));
+    let code = form.field_with_initial("code", draft.code, required(Msg::CodeRequired));
+    let brand = form.field_with_initial("brand", draft.brand, |v: &str| Brand::from_code(v).ok_or(Msg::ChooseBrand));
+
+    // Unticked products have no price to check
+    let products = CommodityKind::ALL.map(|kind| {
+        let saved = draft.commodities.iter().find(|c| c.kind == kind);
+        let selected = RwSignal::new(saved.is_some_and(|c| c.selected));
+        let initial = saved.map(|c| c.price.clone()).unwrap_or_default();
+        let price = form.field_with_initial(price_field(kind), initial, move |v: &str| {
+            if !selected.get() {
+                return Ok(None);
+            }
+            let price = Money::parse(v).map_err(MoneyError::msg)?;
+            if price.is_positive() { Ok(Some(price)) } else { Err(Msg::PriceZero) }
+        });
+        ProductRow { kind, selected, price }
+    });
+
+    // Too big for the draft, so these only last as long as the page
+    let photo = RwSignal::new(None::<String>);
+    let logo = RwSignal::new(None::<String>);
+
+    // Save on every change; registering clears it
+    Effect::new(move |_| {
+        SignupDraft {
+            step: step.get(),
+            name: name.value(),
+            address: address.value(),
+            code: code.value(),
+            brand: brand.value(),
+            email: email.value(),
+            phone: phone.value(),
+            commodities: products
+                .iter()
+                .map(|p| DraftCommodity { kind: p.kind, selected: p.selected.get(), price: p.price.value() })
+                .collect(),
+        }
+        .save();
+    });
+
+    // The draft doesn't keep the password or photos, so let the browser ask first
+    let unload_guard = window_event_listener(leptos::ev::beforeunload, move |ev| {
+        if form.is_dirty() || photo.with_untracked(Option::is_some) || logo.with_untracked(Option::is_some) {
+            ev.prevent_default();
+        }
+    });
+    on_cleanup(move || unload_guard.remove());
+
+    let submit = form.on_submit(move || {
+        let mut commodities = Vec::new();
+        for p in products {
+            if let Some(price) = p.price.parse()? {
+                commodities.push(InitialCommodity { name: p.kind, price });
+            }
+        }
+        if commodities.is_empty() {
+            return Err(Msg::SelectCommodity);
+        }
+        let data = RegisterFormData {
+            name: name.parse()?,
+            address: address.parse()?.to_lowercase(),
+            email: email.parse()?,
+            phone: phone.parse()?,
+            password: password.parse()?,
+            code: code.parse()?,
+            brand: brand.parse()?,
+            commodities,
+            photo: photo.get_untracked(),
+            logo: logo.get_untracked(),
+        };
+        usable_fix(fix.get_untracked())?;
+        let (lat, lon) = pin.get_untracked();
+        Ok((data, lat, lon))
+    });
+
+    let step_ok = move |s: Step| match s {
+        Step::Location => usable_fix(fix.get_untracked()).map_err(|msg| step_error.set(Some(msg))).is_ok(),
+        Step::Commodities => {
+            let prices_ok = form.validate(&CommodityKind::ALL.map(price_field));
+            let any_sold = products.iter().any(|p| p.selected.get_untracked());
+            if !any_sold {
+                step_error.set(Some(Msg::SelectCommodity));
+            }
+            prices_ok && any_sold
+        }
+        _ => form.validate(s.fields()),
+    };
+    let go_to = move |s: Step| {
+        step_error.set(None);
+        step.set(s);
+    };
+
+    // Enter on an earlier step moves on rather than registering; on the last
+    // step anything still wrong sends the owner back to where it is
+    let on_submit = move |ev: SubmitEvent| {
+        let current = step.get_untracked();
+        if current != Step::Review {
+            ev.prevent_default();
+            if step_ok(current) {
+                go_to(current.next());
+            }
+            return;
+        }
+        if let Some(bad) = Step::ALL.into_iter().find(|s| !step_ok(*s)) {
+            ev.prevent_default();
+            step.set(bad);
+            return;
+        }
+        submit(ev);
+    };
+
+    let start_over = move |_| {
+        SignupDraft::clear();
+        form.clear();
+        products.iter().for_each(|p| p.selected.set(false));
+        photo.set(None);
+        logo.set(None);
+        resumed.set(false);
+        go_to(Step::Business);
+    };
+
+    let business_step = move || view! {
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Name)}</label>
+            <TextInput field=name />
+            <FieldError field=name />
+        </div>
+
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Address)}</label>
+            <TextInput field=address autocomplete="street-address" />
+            <FieldError field=address />
+        </div>
+
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Brand)}</label>
+            <select
+                prop:value=move || brand.value()
+                on:change=move |ev| brand.set(event_target_value(&ev))
+                on:blur=move |_| brand.touch()
+            >
+                <option value="" disabled>{move || i18n.t(Msg::ChooseBrand)}</option>
+                {Brand::ALL.into_iter().map(|b| view! {
+                    <option value=b.code()>{move || i18n.t(b.label())}</option>
+                }).collect_view()}
+            </select>
+            <FieldError field=brand />
+        </div>
+
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::RegistrationCode)}</label>
+            <TextInput field=code />
+            <FieldError field=code />
+        </div>
+
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Photos)}</label>
+            <p class="step-hint">{move || i18n.t(Msg::PhotosHint)}</p>
+            <div class="image-uploads">
+                <div class="image-upload">
+                    <h4>{move || i18n.t(Msg::StationPhoto)}</h4>
+                    <ImagePicker kind=ImageKind::Photo value=photo current=None />
+                </div>
+                <div class="image-upload">
+                    <h4>{move || i18n.t(Msg::StationLogo)}</h4>
+                    <ImagePicker kind=ImageKind::Logo value=logo current=None />
+                </div>
+            </div>
+        </div>
+    };
+
+    let contact_step = move || view! {
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Email)}</label>
+            <TextInput field=email kind="email" autocomplete="email" />
+            <FieldError field=email />
+        </div>
+
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Phone)}</label>
+            <TextInput field=phone kind="tel" autocomplete="tel" placeholder="0803 123 4567" />
+            <FieldError field=phone />
+        </div>
+
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::Password)}</label>
+            <div class="password-wrapper">
+                // 2. Dynamic type based on show_password signal
+                <TextInput
+                    field=password
+                    kind=Signal::derive(move || if show_password.get() { "text" } else { "password" })
+                    autocomplete="new-password"
+                    attr:style="width: 100%; padding-right: 40px;"
+                />
+                // 3. Eye Toggle Button
+                <button
+                    type="button"
+                    class="password-toggle"
+                    on:click=move |_| show_password.update(|v| *v = !*v)
+                >
+                    {move || if show_password.get() { i18n.t(Msg::HidePassword) } else { i18n.t(Msg::ShowPassword) }}
+                </button>
+            </div>
+            <PasswordStrengthMeter password=Signal::derive(move || password.value()) />
+            <FieldError field=password />
+        </div>
+    };
+
+    let location_step = move || view! {
+        <div class="form-group">
+            <label>{move || i18n.t(Msg::LocationTitle)}</label>
+            {move || match (locate_action.pending().get(), located.get()) {
+                (true, _) | (false, None) => view! { <p>{i18n.t(Msg::LocatingYou)}</p> }.into_any(),
+                (false, Some(Ok(fix))) => {
+                    let too_rough = fix.accuracy_m > MAX_FIX_ACCURACY_M;
+                    view! {
+                        <LocationPicker fix=fix pin=pin />
+                        <small class="location-accuracy" class:error-message=too_rough>
+                            {i18n.t(Msg::AccuracyWithin).replace("{meters}", &format!("{:.0}", fix.accuracy_m))}
+                        </small>
+                    }.into_any()
+                }
+                (false, Some(Err(e))) => view! {
+                    <small class="error-message">{i18n.t(e.msg())}</small>
+                }.into_any(),
+            }}
+            <button
+                type="button"
+                class="link-button"
+                disabled=move || locate_action.pending().get()
+                on:click=move |_| {
+                    step_error.set(None);
+                    locate_action.dispatch(());
+                }
+            >
+                {move || i18n.t(Msg::RetryLocation)}
+            </button>
+        </div>
+    };
+
+    let commodities_step = move || view! {
+        <p class="step-hint">{move || i18n.t(Msg::CommoditiesHint)}</p>
+        {products.into_iter().map(|p| view! {
+            <div class="form-group product-row">
+                <label class="product-choice">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || p.selected.get()
+                        on:change=move |ev| {
+                            step_error.set(None);
+                            p.selected.set(event_target_checked(&ev));
+                        }
+                    />
+                    {move || i18n.t(p.kind.label())}
+                </label>
+                <TextInput
+                    field=p.price
+                    placeholder="650"
+                    attr:inputmode="decimal"
+                    attr:disabled=move || !p.selected.get()
+                />
+                <FieldError field=p.price />
+            </div>
+        }).collect_view()}
+    };
+
+    let review_row = move |label: Msg, value: String, edit: Step| view! {
+        <dt>{move || i18n.t(label)}</dt>
+        <dd>
+            <span>{value}</span>
+            <button type="button" class="link-button" on:click=move |_| go_to(edit)>
+                {move || i18n.t(Msg::Edit)}
+            </button>
+        </dd>
+    };
+
+    let review_step = move || {
+        let (lat, lon) = pin.get_untracked();
+        let sold = products
+            .iter()
+            .filter_map(|p| p.price.parse().ok().flatten().map(|price| {
+                format!("{} {}", p.kind.code().to_uppercase(), i18n.format_naira(price))
+            }))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let images = [(photo, Msg::StationPhoto), (logo, Msg::StationLogo)]
+            .into_iter()
+            .filter(|(image, _)| image.with(Option::is_some))
+            .map(|(_, label)| i18n.t(label).to_string())
+            .collect::<Vec<_>>();
+        let images = if images.is_empty() { i18n.t(Msg::NoneAdded).to_string() } else { images.join(", ") };
+        view! {
+            <p>{move || i18n.t(Msg::ReviewHint)}</p>
+            <dl class="review-list">
+                {review_row(Msg::Name, name.value(), Step::Business)}
+                {review_row(Msg::Address, address.value(), Step::Business)}
+                {review_row(Msg::Brand, brand.parse().map(|b| i18n.t(b.label()).to_string()).unwrap_or_default(), Step::Business)}
+                {review_row(Msg::RegistrationCode, code.value(), Step::Business)}
+                {review_row(Msg::Email, email.value(), Step::Contact)}
+                {review_row(Msg::Phone, phone.value(), Step::Contact)}
+                {review_row(Msg::LocationTitle, format!("{lat:.5}, {lon:.5}"), Step::Location)}
+                {review_row(Msg::StepCommodities, sold, Step::Commodities)}
+                {review_row(Msg::Photos, images, Step::Business)}
+            </dl>
+        }
+    };
+
+    view! {
+        <div class="form-container">
+            <h4>{move || i18n.t(Msg::SignupNote)}</h4>
+            <h2>{move || i18n.t(Msg::RegisterStationTitle)}</h2>
+
+            <WizardProgress step=step />
+
+            <Show when=move || resumed.get()>
+                <p class="draft-notice">
+                    {move || i18n.t(Msg::DraftRestored)}
+                    " "
+                    <button type="button" class="link-button" on:click=start_over>
+                        {move || i18n.t(Msg::StartOver)}
+                    </button>
+                </p>
+            </Show>
+
+            <form on:submit=on_submit>
+                {move || match step.get() {
+                    Step::Business => business_step().into_any(),
+                    Step::Contact => contact_step().into_any(),
+                    Step::Location => location_step().into_any(),
+                    Step::Commodities => commodities_step().into_any(),
+                    Step::Review => review_step().into_any(),
+                }}
+
+                {move || step_error.get().or_else(|| form.error()).map(|msg| view! {
+                    <small class="error-message">{i18n.t(msg)}</small>
+                })}
+
+                <div class="wizard-buttons">
+                    <Show when=move || step.get() != Step::Business>
+                        <button type="button" class="secondary-button" on:click=move |_| go_to(step.get_untracked().prev())>
+                            {move || i18n.t(Msg::Back)}
+                        </button>
+                    </Show>
+                    <button type="submit" class="submit-button" disabled=move || form.pending()>
+                        {move || match (step.get(), form.pending()) {
+                            (Step::Review, true) => i18n.t(Msg::Registering),
+                            (Step::Review, false) => i18n.t(Msg::Register),
+                            _ => i18n.t(Msg::Next),
+                        }}
+                    </button>
+                </div>
+            </form>
+
+            <p>
+                {move || i18n.t(Msg::AlreadyRegistered)}
+                <A href="/signin">{move || i18n.t(Msg::Login)}</A>
+            </p>
+            {move || register_action.value().get().and_then(|res| res.err()).map(|err| view! {
+                <small class="error-message">{err.text(i18n)}</small>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,73 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{Msg, use_i18n};
+
+/// The signup wizard's pages, in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    #[default]
+    Business,
+    Contact,
+    Location,
+    Commodities,
+    Review,
+}
+
+impl Step {
+    pub const ALL: [Step; 5] = [Step::Business, Step::Contact, Step::Location, Step::Commodities, Step::Review];
+
+    pub fn label(self) -> Msg {
+        match self {
+            Step::Business => Msg::StepBusiness,
+            Step::Contact => Msg::StepContact,
+            Step::Location => Msg::StepLocation,
+            Step::Commodities => Msg::StepCommodities,
+            Step::Review => Msg::StepReview,
+        }
+    }
+
+    /// Form fields that have to pass before leaving this step. The location
+    /// and product steps are checked by the page itself.
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            Step::Business => &["name", "address", "brand", "code"],
+            Step::Contact => &["email", "phone", "password"],
+            Step::Location | Step::Commodities | Step::Review => &[],
+        }
+    }
+
+    fn index(self) -> usize {
+        Step::ALL.iter().position(|s| *s == self).unwrap_or_default()
+    }
+
+    pub fn next(self) -> Step {
+        Step::ALL.get(self.index() + 1).copied().unwrap_or(self)
+    }
+
+    pub fn prev(self) -> Step {
+        self.index().checked_sub(1).map_or(self, |i| Step::ALL[i])
+    }
+}
+
+/// Numbered list of steps with the finished ones ticked off
+#[component]
+pub fn WizardProgress(#[prop(into)] step: Signal<Step>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <ol class="wizard-progress">
+            {Step::ALL.into_iter().enumerate().map(|(i, s)| view! {
+                <li
+                    class:done=move || s.index() < step.get().index()
+                    class:current=move || s == step.get()
+                    aria-current=move || (s == step.get()).then_some("step")
+                >
+                    <span class="step-number">{i + 1}</span>
+                    <span class="step-label">{move || i18n.t(s.label())}</span>
+                </li>
+            }).collect_view()}
+        </ol>
+    }
+}
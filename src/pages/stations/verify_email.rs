@@ -0,0 +1,119 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_query_map};
+
+use crate::i18n::{Msg, use_i18n};
+use crate::pages::stations::dto::{resend_verification, verify_email};
+use crate::utils::countdown::use_countdown;
+use crate::utils::protect_route::is_authenticated;
+
+// Matches the API's own resend throttle, so the button is never enabled just to be rejected
+const RESEND_COOLDOWN_SECS: u64 = 60;
+
+/// "Resend link" button that locks itself for a minute after each send
+#[component]
+pub fn ResendVerification(email: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let cooldown = use_countdown();
+    let email = StoredValue::new(email);
+
+    let resend_action = Action::new_local(move |_: &()| async move {
+        let result = resend_verification(email.get_value()).await;
+        if result.is_ok() {
+            cooldown.start(RESEND_COOLDOWN_SECS);
+        }
+        result
+    });
+
+    view! {
+        <button
+            type="button"
+            class="edit-button"
+            disabled=move || resend_action.pending().get() || cooldown.is_running()
+            on:click=move |_| { resend_action.dispatch(()); }
+        >
+            {move || if resend_action.pending().get() {
+                i18n.t(Msg::Sending).to_string()
+            } else if cooldown.is_running() {
+                i18n.t(Msg::ResendIn).replace("{seconds}", &cooldown.remaining().to_string())
+            } else {
+                i18n.t(Msg::ResendLink).to_string()
+            }}
+        </button>
+        {move || match resend_action.value().get() {
+            Some(Ok(())) => Some(view! { <small class="success-message">{i18n.t(Msg::LinkResent)}</small> }.into_any()),
+            Some(Err(err)) => Some(view! { <small class="error-message">{err}</small> }.into_any()),
+            None => None,
+        }}
+    }
+}
+
+/// Shown straight after signup: `/check-email?email=`
+#[component]
+pub fn CheckEmail() -> impl IntoView {
+    let i18n = use_i18n();
+    let query = use_query_map();
+    let email = move || query.read().get("email").unwrap_or_default();
+
+    view! {
+        <div class="form-container">
+            <h2>{move || i18n.t(Msg::CheckEmailTitle)}</h2>
+            <p>{move || i18n.t(Msg::CheckEmailBody).replace("{email}", &email())}</p>
+            {move || {
+                let email = email();
+                (!email.is_empty()).then(|| view! { <ResendVerification email=email /> })
+            }}
+            <p><A href="/signin">{move || i18n.t(Msg::GoToLogin)}</A></p>
+        </div>
+    }
+}
+
+/// Landing page for the link in the verification email: `/verify-email?token=`
+#[component]
+pub fn VerifyEmail() -> impl IntoView {
+    let i18n = use_i18n();
+    let query = use_query_map();
+
+    let verification = LocalResource::new(move || {
+        let token = query.read().get("token").unwrap_or_default();
+        async move {
+            if token.is_empty() {
+                return Err(i18n.t_untracked(Msg::VerificationLinkInvalid).to_string());
+            }
+            verify_email(token).await
+        }
+    });
+
+    view! {
+        <div class="form-container">
+            <Suspense fallback=move || view! { <p class="loading">{i18n.t(Msg::VerifyingEmail)}</p> }>
+                {move || verification.get().map(|res| match res {
+                    Ok(()) => view! {
+                        <p class="success-message">{i18n.t(Msg::EmailVerified)}</p>
+                        {if is_authenticated() {
+                            view! { <A href="/station">{i18n.t(Msg::GoToDashboard)}</A> }.into_any()
+                        } else {
+                            view! { <A href="/signin">{i18n.t(Msg::GoToLogin)}</A> }.into_any()
+                        }}
+                    }.into_any(),
+                    Err(err) => view! {
+                        <p class="error-message">{err}</p>
+                        <A href="/signin">{i18n.t(Msg::GoToLogin)}</A>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Dashboard nag for stations that haven't clicked their verification link yet
+#[component]
+pub fn UnverifiedBanner(email: String) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="unverified-banner">
+            <p>{move || i18n.t(Msg::UnverifiedBanner)}</p>
+            <ResendVerification email=email />
+        </div>
+    }
+}
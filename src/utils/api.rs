@@ -0,0 +1,85 @@
+use gloo_net::http::{Method, Request, RequestBuilder, Response};
+use serde::Deserialize;
+
+use crate::utils::base_url::BaseUrl;
+use crate::utils::session::SessionContext;
+
+/// What `/auth/refresh` answers
+#[derive(Deserialize, Clone)]
+pub struct RefreshedTokens {
+    pub access_token: String,
+    /// Missing when the API doesn't rotate refresh tokens
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+/// Swaps a refresh token for new tokens. Lives here rather than with the other
+/// auth calls because the session itself needs it.
+pub async fn refresh_tokens(refresh_token: &str) -> Result<RefreshedTokens, String> {
+    let base_url = BaseUrl::get_base_url();
+    let url = format!("{base_url}/api/v1/auth/refresh");
+    let payload = serde_json::json!({ "refresh_token": refresh_token });
+    let resp = Request::post(url.as_str())
+        .header("Content-Type", "application/json")
+        .json(&payload)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if resp.ok() {
+        resp.json::<RefreshedTokens>().await.map_err(|e| format!("Parsing error: {}", e))
+    } else {
+        Err(format!("Server error: {}", resp.status()))
+    }
+}
+
+async fn send(method: Method, url: &str, body: Option<&serde_json::Value>, token: &str) -> Result<Response, String> {
+    let builder = RequestBuilder::new(url)
+        .method(method)
+        .header("Authorization", &format!("Bearer {token}"));
+
+    let request = match body {
+        Some(body) => builder.json(body).map_err(|e| e.to_string())?,
+        None => builder.build().map_err(|e| e.to_string())?,
+    };
+    request.send().await.map_err(|e| format!("Network error: {}", e))
+}
+
+/// Sends a request with the station's bearer token. On a 401 the session is
+/// refreshed and the request retried once; a second 401 is returned as is.
+pub async fn send_authed(
+    session: SessionContext,
+    method: Method,
+    url: &str,
+    body: Option<serde_json::Value>,
+) -> Result<Response, String> {
+    let token = session.valid_token().await?;
+    let resp = send(method.clone(), url, body.as_ref(), &token).await?;
+    if resp.status() != 401 {
+        return Ok(resp);
+    }
+
+    // Another request may have refreshed while this one was in flight
+    let retry_token = match session.token_untracked() {
+        Some(current) if current != token => current,
+        _ => session.refresh().await?.token,
+    };
+    send(method, url, body.as_ref(), &retry_token).await
+}
+
+/// Seconds from a `Retry-After` header, which is either a number of seconds or
+/// an HTTP date. Cross-origin, the API has to list it in
+/// `Access-Control-Expose-Headers` for us to see it at all.
+pub fn retry_after_secs(resp: &Response) -> Option<u64> {
+    let value = resp.headers().get("Retry-After")?;
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(secs);
+    }
+    let at_ms = js_sys::Date::parse(value);
+    if at_ms.is_nan() {
+        return None;
+    }
+    Some(((at_ms - js_sys::Date::now()) / 1000.0).ceil().max(0.0) as u64)
+}
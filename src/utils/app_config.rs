@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures::future::{Either, select};
+use gloo_net::http::Request;
+use leptos::{logging, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::base_url::BaseUrl;
+
+// Served next to index.html, so each environment only needs its own copy of this file
+const CONFIG_PATH: &str = "/config.json";
+
+static CONFIG: OnceLock<AppConfig> = OnceLock::new();
+
+// The app isn't mounted until the config is in, so don't wait long on a slow host
+const LOAD_TIMEOUT: Duration = Duration::from_secs(3);
+
+const DEFAULT_TILE_URL: &str = "https://tile.openstreetmap.org/{z}/{x}/{y}.png";
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct SupportContact {
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AppConfig {
+    pub api_base_url: String,
+    pub enabled_regions: Vec<String>,
+    pub features: HashMap<String, bool>,
+    pub support: SupportContact,
+    /// Raster tiles for the signup location picker, `{z}/{x}/{y}` style
+    pub map_tile_url: String,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            api_base_url: BaseUrl::fallback(),
+            enabled_regions: vec!["abuja".to_string()],
+            features: HashMap::new(),
+            support: SupportContact::default(),
+            map_tile_url: DEFAULT_TILE_URL.to_string(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Fetches `config.json`. A missing, broken or slow file is not fatal, we
+    /// just run with the values baked in at compile time.
+    pub async fn load() -> Self {
+        match Self::fetch_with_timeout().await {
+            Ok(config) => config.normalized(),
+            Err(e) => {
+                logging::warn!("Using built-in config: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    async fn fetch_with_timeout() -> Result<Self, String> {
+        let (tx, rx) = oneshot::channel::<()>();
+        set_timeout(move || { let _ = tx.send(()); }, LOAD_TIMEOUT);
+        match select(Box::pin(Self::fetch()), rx).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(format!("No answer within {}s", LOAD_TIMEOUT.as_secs())),
+        }
+    }
+
+    async fn fetch() -> Result<Self, String> {
+        let resp = Request::get(CONFIG_PATH)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {}", e))?;
+
+        if resp.ok() {
+            resp.json::<Self>().await.map_err(|e| format!("Parsing error: {}", e))
+        } else {
+            Err(format!("Server error: {}", resp.status()))
+        }
+    }
+
+    fn normalized(mut self) -> Self {
+        self.api_base_url = self.api_base_url.trim().trim_end_matches('/').to_string();
+        if self.api_base_url.is_empty() {
+            self.api_base_url = BaseUrl::fallback();
+        }
+        self
+    }
+
+    /// Makes the loaded config available to non-reactive code (API helpers).
+    /// Only the first call wins.
+    pub fn install(self) {
+        if CONFIG.set(self).is_err() {
+            logging::warn!("App config was already installed");
+        }
+    }
+
+    pub fn current() -> &'static AppConfig {
+        CONFIG.get_or_init(AppConfig::default)
+    }
+
+    pub fn is_enabled(&self, feature: &str) -> bool {
+        self.features.get(feature).copied().unwrap_or(false)
+    }
+}
+
+/// The config provided by `App`, falling back to the global copy outside of it.
+pub fn use_app_config() -> AppConfig {
+    use_context::<AppConfig>().unwrap_or_else(|| AppConfig::current().clone())
+}
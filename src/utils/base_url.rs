@@ -1,11 +1,17 @@
-
 // pub const BASE_URL: &str = "https://close-siusan-jaesea-21c201ce.koyeb.app";
 
+use crate::utils::app_config::AppConfig;
+
 pub struct BaseUrl;
 impl BaseUrl{
     // const BASE_URL: &str = "https://close-siusan-jaesea-21c201ce.koyeb.app";
     const BASE_URL: &str = "http://localhost:8000";
     pub fn get_base_url() -> String {
+        AppConfig::current().api_base_url.clone()
+    }
+
+    /// Compile-time value, only used when `config.json` doesn't provide one
+    pub fn fallback() -> String {
         option_env!("BASE_URL").map(|res| res.to_string()).unwrap_or_else(|| Self::BASE_URL.to_string())
     }
 }
@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use leptos_use::use_interval_fn;
+
+/// Seconds-remaining timer for cooldowns ("resend in 42s", "try again in 1:30").
+/// Ticks once a second while the owning component is mounted.
+#[derive(Clone, Copy)]
+pub struct Countdown {
+    until_ms: RwSignal<f64>,
+    now_ms: RwSignal<f64>,
+}
+
+impl Countdown {
+    pub fn start(&self, secs: u64) {
+        let now = js_sys::Date::now();
+        self.now_ms.set(now);
+        self.until_ms.set(now + secs as f64 * 1000.0);
+    }
+
+    pub fn remaining(&self) -> u64 {
+        ((self.until_ms.get() - self.now_ms.get()) / 1000.0).ceil().max(0.0) as u64
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.remaining() > 0
+    }
+
+    /// "42s" under a minute, "14:05" above
+    pub fn label(&self) -> String {
+        let secs = self.remaining();
+        if secs < 60 {
+            format!("{secs}s")
+        } else {
+            format!("{}:{:02}", secs / 60, secs % 60)
+        }
+    }
+}
+
+pub fn use_countdown() -> Countdown {
+    let countdown = Countdown {
+        until_ms: RwSignal::new(0.0),
+        now_ms: RwSignal::new(0.0),
+    };
+    use_interval_fn(
+        move || {
+            if countdown.until_ms.get_untracked() > countdown.now_ms.get_untracked() {
+                countdown.now_ms.set(js_sys::Date::now());
+            }
+        },
+        1000,
+    );
+    countdown
+}
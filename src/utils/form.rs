@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use leptos::prelude::*;
+
+use crate::i18n::Msg;
+use crate::utils::validation::ValidationError;
+
+type Parser<T> = Arc<dyn Fn(&str) -> Result<T, Msg> + Send + Sync>;
+
+/// The untyped half of a field, which is all the form needs to touch, reset
+/// and check it as a group.
+#[derive(Clone, Copy)]
+struct FieldState {
+    name: &'static str,
+    value: RwSignal<String>,
+    initial: StoredValue<String>,
+    touched: RwSignal<bool>,
+    is_valid: Signal<bool>,
+}
+
+impl FieldState {
+    fn is_dirty(&self) -> bool {
+        self.value.with(|v| self.initial.with_value(|i| v != i))
+    }
+}
+
+/// One input's raw text plus how to turn it into a `T`. Errors are only shown
+/// once the field has been touched (blurred, or the form submitted), so users
+/// aren't told off while they're still typing.
+pub struct Field<T: 'static> {
+    name: &'static str,
+    state: FieldState,
+    parser: StoredValue<Parser<T>>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Field<T> {}
+
+impl<T: 'static> Field<T> {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn value(&self) -> String {
+        self.state.value.get()
+    }
+
+    pub fn set(&self, value: String) {
+        self.state.value.set(value);
+    }
+
+    pub fn touch(&self) {
+        self.state.touched.set(true);
+    }
+
+    /// The typed value, for building the submit payload
+    pub fn parse(&self) -> Result<T, Msg> {
+        let value = self.state.value.get_untracked();
+        self.parser.with_value(|parse| parse(&value))
+    }
+
+    /// Reactive; `None` until touched
+    pub fn error(&self) -> Option<Msg> {
+        if !self.state.touched.get() {
+            return None;
+        }
+        self.state.value.with(|v| self.parser.with_value(|parse| parse(v).err()))
+    }
+}
+
+/// A set of fields submitted through one `Action`. Pending state comes from the
+/// action, so a form can't be sent twice while a request is in flight.
+pub struct Form<I: 'static, O: 'static> {
+    fields: StoredValue<Vec<FieldState>>,
+    action: Action<I, O>,
+    error: RwSignal<Option<Msg>>,
+}
+
+impl<I, O> Clone for Form<I, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, O> Copy for Form<I, O> {}
+
+impl<I, O> Form<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    pub fn new(action: Action<I, O>) -> Self {
+        Self { fields: StoredValue::new(Vec::new()), action, error: RwSignal::new(None) }
+    }
+
+    /// Adds a field that starts empty
+    pub fn field<T: 'static>(
+        &self,
+        name: &'static str,
+        parser: impl Fn(&str) -> Result<T, Msg> + Send + Sync + 'static,
+    ) -> Field<T> {
+        self.field_with_initial(name, String::new(), parser)
+    }
+
+    pub fn field_with_initial<T: 'static>(
+        &self,
+        name: &'static str,
+        initial: String,
+        parser: impl Fn(&str) -> Result<T, Msg> + Send + Sync + 'static,
+    ) -> Field<T> {
+        let parser: Parser<T> = Arc::new(parser);
+        let value = RwSignal::new(initial.clone());
+        let check = parser.clone();
+        let state = FieldState {
+            name,
+            value,
+            initial: StoredValue::new(initial),
+            touched: RwSignal::new(false),
+            is_valid: Signal::derive(move || value.with(|v| check(v).is_ok())),
+        };
+        self.fields.update_value(|fields| fields.push(state));
+        Field { name, state, parser: StoredValue::new(parser) }
+    }
+
+    pub fn pending(&self) -> bool {
+        self.action.pending().get()
+    }
+
+    /// Why the last submit's `build` refused, for checks that span more than
+    /// one field or live outside the fields
+    pub fn error(&self) -> Option<Msg> {
+        self.error.get()
+    }
+
+    /// Any field changed from the value it started with
+    pub fn is_dirty(&self) -> bool {
+        self.fields.with_value(|fields| fields.iter().any(FieldState::is_dirty))
+    }
+
+    /// Touches the named fields and says whether they all pass, for checking
+    /// one step of a multi-step form before moving on
+    pub fn validate(&self, names: &[&str]) -> bool {
+        self.fields.with_value(|fields| {
+            fields
+                .iter()
+                .filter(|f| names.contains(&f.name))
+                .fold(true, |ok, f| {
+                    f.touched.set(true);
+                    ok && f.is_valid.get_untracked()
+                })
+        })
+    }
+
+    /// Back to the initial values with nothing touched
+    pub fn reset(&self) {
+        self.fields.with_value(|fields| {
+            for f in fields {
+                f.value.set(f.initial.get_value());
+                f.touched.set(false);
+            }
+        });
+    }
+
+    /// Empties every field and makes empty the new starting point, for
+    /// throwing away values the form was opened with
+    pub fn clear(&self) {
+        self.fields.with_value(|fields| {
+            for f in fields {
+                f.initial.set_value(String::new());
+                f.value.set(String::new());
+                f.touched.set(false);
+            }
+        });
+    }
+
+    /// Submit handler: shows every field's error, and only when all of them
+    /// pass builds the payload with `build` and dispatches it.
+    pub fn on_submit(
+        &self,
+        build: impl Fn() -> Result<I, Msg> + Copy + 'static,
+    ) -> impl Fn(leptos::ev::SubmitEvent) + Copy + 'static {
+        let form = *self;
+        move |ev| {
+            ev.prevent_default();
+            form.fields.with_value(|fields| fields.iter().for_each(|f| f.touched.set(true)));
+            let all_valid = form.fields.with_value(|fields| fields.iter().all(|f| f.is_valid.get_untracked()));
+            if !all_valid || form.action.pending().get_untracked() {
+                return;
+            }
+            match build() {
+                Ok(input) => {
+                    form.error.set(None);
+                    form.action.dispatch(input);
+                }
+                Err(msg) => form.error.set(Some(msg)),
+            }
+        }
+    }
+}
+
+/// Trimmed text that must not be empty
+pub fn required(msg: Msg) -> impl Fn(&str) -> Result<String, Msg> + Send + Sync + 'static {
+    move |value| {
+        let value = value.trim();
+        if value.is_empty() { Err(msg) } else { Ok(value.to_string()) }
+    }
+}
+
+/// Adapts the `utils::validation` checks to field parsers
+pub fn check<T>(
+    validate: impl Fn(&str) -> Result<T, ValidationError> + Send + Sync + 'static,
+) -> impl Fn(&str) -> Result<T, Msg> + Send + Sync + 'static {
+    move |value| validate(value).map_err(ValidationError::msg)
+}
@@ -1,58 +1,304 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use wasm_bindgen::prelude::*;
-use web_sys::{window, Position, PositionOptions}; // Added PositionOptions
+use web_sys::{window, Geolocation, Position, PositionError, PositionOptions};
 use futures::channel::oneshot;
+use futures::future::LocalBoxFuture;
 use leptos::logging;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Msg;
+
+const LAST_FIX_KEY: &str = "last_gps_fix";
+#[cfg(debug_assertions)]
+const MOCK_PARAM: &str = "mock_gps";
+
+/// A position reading and how far off the device thinks it might be
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GpsFix {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// 95% confidence radius in metres, as reported by the browser
+    pub accuracy_m: f64,
+    /// Metres above the WGS84 ellipsoid; most phones only report it outdoors
+    pub altitude_m: Option<f64>,
+    /// When the reading was taken, ms since the epoch. With `maximum_age` set
+    /// this can be older than the call that returned it.
+    pub timestamp_ms: f64,
+}
+
+impl GpsFix {
+    /// Seconds since the reading was taken
+    pub fn age_secs(&self) -> f64 {
+        ((js_sys::Date::now() - self.timestamp_ms) / 1000.0).max(0.0)
+    }
+}
+
+/// Why `locate` came back without a position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GpsError {
+    PermissionDenied,
+    PositionUnavailable,
+    Timeout,
+    /// No `navigator.geolocation`, e.g. an insecure origin or an old browser
+    Unsupported,
+}
+
+impl GpsError {
+    /// `GeolocationPositionError.code`
+    fn from_code(code: u16) -> Self {
+        match code {
+            1 => GpsError::PermissionDenied,
+            3 => GpsError::Timeout,
+            _ => GpsError::PositionUnavailable,
+        }
+    }
+
+    pub fn msg(self) -> Msg {
+        match self {
+            GpsError::PermissionDenied => Msg::GpsPermissionDenied,
+            GpsError::PositionUnavailable => Msg::GpsFailed,
+            GpsError::Timeout => Msg::GpsTimeout,
+            GpsError::Unsupported => Msg::GpsUnsupported,
+        }
+    }
+}
+
+/// Somewhere a single position reading can come from
+pub trait LocationProvider {
+    fn locate(&self) -> LocalBoxFuture<'static, Result<GpsFix, GpsError>>;
+}
+
+/// The device's own GPS through `navigator.geolocation`
+pub struct BrowserLocation;
+
+/// Keeps a `watchPosition` alive. Dropping it clears the watch before the
+/// callbacks are freed, so the browser can never call into a dropped closure.
+struct Watch {
+    geolocation: Geolocation,
+    id: i32,
+    _on_success: Closure<dyn FnMut(Position)>,
+    _on_error: Closure<dyn FnMut(PositionError)>,
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        self.geolocation.clear_watch(self.id);
+    }
+}
+
+impl LocationProvider for BrowserLocation {
+    fn locate(&self) -> LocalBoxFuture<'static, Result<GpsFix, GpsError>> {
+        Box::pin(async move {
+            let window = window().ok_or(GpsError::Unsupported)?;
+            let geolocation = window.navigator().geolocation().map_err(|_| GpsError::Unsupported)?;
+
+            let (tx, rx) = oneshot::channel::<Result<GpsFix, GpsError>>();
+
+            // Both callbacks share the one sender; whichever the browser calls first uses it
+            let tx_success = Rc::new(RefCell::new(Some(tx)));
+            let tx_error = tx_success.clone();
+
+            // Mobile GPS can be slow, so we give it 10s, and a reading from the
+            // last minute is good enough to skip waiting for a new one
+            let options = PositionOptions::new();
+            options.set_enable_high_accuracy(true);
+            options.set_timeout(10000);
+            options.set_maximum_age(60000);
+
+            let on_success = Closure::<dyn FnMut(Position)>::new(move |pos: Position| {
+                let coords = pos.coords();
+                let fix = GpsFix {
+                    latitude: coords.latitude(),
+                    longitude: coords.longitude(),
+                    accuracy_m: coords.accuracy(),
+                    altitude_m: coords.altitude(),
+                    timestamp_ms: pos.timestamp(),
+                };
+                logging::log!("GPS Fixed: {}, {} (±{}m)", fix.latitude, fix.longitude, fix.accuracy_m);
+                if let Some(sender) = tx_success.borrow_mut().take() {
+                    let _ = sender.send(Ok(fix));
+                }
+            });
+
+            let on_error = Closure::<dyn FnMut(PositionError)>::new(move |err: PositionError| {
+                logging::error!("Geolocation Error: {} ({})", err.message(), err.code());
+                if let Some(sender) = tx_error.borrow_mut().take() {
+                    let _ = sender.send(Err(GpsError::from_code(err.code())));
+                }
+            });
+
+            // A watch rather than getCurrentPosition because only a watch can
+            // be cancelled, which is what lets us free the callbacks
+            let id = geolocation
+                .watch_position_with_error_callback_and_options(
+                    on_success.as_ref().unchecked_ref(),
+                    Some(on_error.as_ref().unchecked_ref()),
+                    &options,
+                )
+                .map_err(|_| GpsError::Unsupported)?;
+            let _watch = Watch { geolocation, id, _on_success: on_success, _on_error: on_error };
+
+            // A dropped sender can't happen while the watch is alive, but
+            // treat it like the browser giving up
+            rx.await.unwrap_or(Err(GpsError::PositionUnavailable))
+        })
+    }
+}
+
+/// Always answers with the same result, for exercising the location flows
+/// without a GPS. Only picked from the URL in dev builds.
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+pub struct MockLocation(pub Result<GpsFix, GpsError>);
+
+impl LocationProvider for MockLocation {
+    fn locate(&self) -> LocalBoxFuture<'static, Result<GpsFix, GpsError>> {
+        let result = self.0;
+        Box::pin(async move { result })
+    }
+}
+
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+impl MockLocation {
+    /// `lat,lon[,accuracy_m]` for a fix taken at `now_ms`, or one of
+    /// `denied`, `timeout`, `unavailable`, `unsupported` for a failure
+    pub fn parse(spec: &str, now_ms: f64) -> Option<Self> {
+        let error = match spec.trim() {
+            "denied" => Some(GpsError::PermissionDenied),
+            "timeout" => Some(GpsError::Timeout),
+            "unavailable" => Some(GpsError::PositionUnavailable),
+            "unsupported" => Some(GpsError::Unsupported),
+            _ => None,
+        };
+        if let Some(error) = error {
+            return Some(MockLocation(Err(error)));
+        }
+
+        let parts = spec
+            .split(',')
+            .map(|p| p.trim().parse::<f64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (latitude, longitude, accuracy_m) = match parts[..] {
+            [lat, lon] => (lat, lon, 10.0),
+            [lat, lon, accuracy] => (lat, lon, accuracy),
+            _ => return None,
+        };
+        let in_range = (-90.0..=90.0).contains(&latitude)
+            && (-180.0..=180.0).contains(&longitude)
+            && accuracy_m >= 0.0;
+        in_range.then_some(MockLocation(Ok(GpsFix {
+            latitude,
+            longitude,
+            accuracy_m,
+            altitude_m: None,
+            timestamp_ms: now_ms,
+        })))
+    }
+}
+
+/// Remembers the last good reading and falls back to it when a fresh one
+/// can't be had. The stored fix keeps its original timestamp, so callers'
+/// staleness checks still apply to it.
+pub struct LastKnownLocation<P> {
+    inner: P,
+}
+
+impl<P: LocationProvider> LastKnownLocation<P> {
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    fn storage() -> Option<web_sys::Storage> {
+        window()?.local_storage().ok().flatten()
+    }
+
+    fn save(fix: &GpsFix) {
+        if let (Some(storage), Ok(json)) = (Self::storage(), serde_json::to_string(fix)) {
+            let _ = storage.set_item(LAST_FIX_KEY, &json);
+        }
+    }
+
+    fn load() -> Option<GpsFix> {
+        let json = Self::storage()?.get_item(LAST_FIX_KEY).ok().flatten()?;
+        serde_json::from_str(&json).ok()
+    }
+}
+
+impl<P: LocationProvider> LocationProvider for LastKnownLocation<P> {
+    fn locate(&self) -> LocalBoxFuture<'static, Result<GpsFix, GpsError>> {
+        let fresh = self.inner.locate();
+        Box::pin(async move {
+            match fresh.await {
+                Ok(fix) => {
+                    Self::save(&fix);
+                    Ok(fix)
+                }
+                // The user said no; an old position would go around that
+                Err(GpsError::PermissionDenied) => Err(GpsError::PermissionDenied),
+                Err(e) => Self::load().ok_or(e),
+            }
+        })
+    }
+}
+
+/// `?mock_gps=...` on the current URL, dev builds only
+#[cfg(debug_assertions)]
+fn mock_from_url() -> Option<MockLocation> {
+    let search = window()?.location().search().ok()?;
+    let spec = search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(MOCK_PARAM)?.strip_prefix('='))?;
+    let spec: String = js_sys::decode_uri_component(spec).ok()?.into();
+    let mock = MockLocation::parse(&spec, js_sys::Date::now());
+    if mock.is_none() {
+        logging::warn!("Ignoring unreadable ?{}={}", MOCK_PARAM, spec);
+    }
+    mock
+}
+
+/// The provider the app should use right now: a `?mock_gps=` override in dev
+/// builds, otherwise the browser with the last known position as a fallback
+pub fn location_provider() -> Box<dyn LocationProvider> {
+    #[cfg(debug_assertions)]
+    if let Some(mock) = mock_from_url() {
+        return Box::new(mock);
+    }
+    Box::new(LastKnownLocation::new(BrowserLocation))
+}
+
+pub async fn locate() -> Result<GpsFix, GpsError> {
+    location_provider().locate().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mock_fixes() {
+        let Some(MockLocation(Ok(fix))) = MockLocation::parse("9.0579, 7.4951", 1_000.0) else {
+            panic!("expected a fix");
+        };
+        assert_eq!((fix.latitude, fix.longitude, fix.accuracy_m, fix.timestamp_ms), (9.0579, 7.4951, 10.0, 1_000.0));
+
+        let Some(MockLocation(Ok(fix))) = MockLocation::parse("9.0579,7.4951,120", 0.0) else {
+            panic!("expected a fix");
+        };
+        assert_eq!(fix.accuracy_m, 120.0);
+    }
+
+    #[test]
+    fn parses_mock_failures() {
+        assert_eq!(MockLocation::parse("denied", 0.0).map(|m| m.0), Some(Err(GpsError::PermissionDenied)));
+        assert_eq!(MockLocation::parse("timeout", 0.0).map(|m| m.0), Some(Err(GpsError::Timeout)));
+    }
 
-pub async fn locate() -> Option<(f64, f64)> {
-    let window = window()?;
-    let navigator = window.navigator();
-    let geolocation = navigator.geolocation().ok()?;
-
-    let (tx, rx) = oneshot::channel::<(f64, f64)>();
-    
-    // We need two Options for the sender because we have two separate closures
-    let mut tx_success = Some(tx);
-
-    // 1. Setup Options: Mobile GPS can be slow, so we set a 10s timeout
-    let options = PositionOptions::new();
-    options.set_enable_high_accuracy(true); 
-    options.set_timeout(10000); // 10 seconds timeout
-    options.set_maximum_age(60000); // Allow 1-minute old cached location for speed
-
-    let success_callback = Closure::wrap(Box::new(move |pos: Position| {
-        let coords = pos.coords();
-        let lat = coords.latitude();
-        let lon = coords.longitude();
-        
-        logging::log!("GPS Fixed: {}, {}", lat, lon);
-
-        if let Some(sender) = tx_success.take() {
-            let _ = sender.send((lat, lon));
+    #[test]
+    fn rejects_unreadable_mocks() {
+        for spec in ["", "9.05", "abuja", "9.05,7.49,5,1", "91,7.49", "9.05,181", "9.05,7.49,-1"] {
+            assert!(MockLocation::parse(spec, 0.0).is_none(), "{spec}");
         }
-    }) as Box<dyn FnMut(Position)>);
-
-    // 2. IMPORTANT: The error callback must exist to unblock the 'rx' channel
-    // We use a separate channel or just let rx fail when tx is dropped.
-    // However, to be safe, we'll let this closure just log.
-    let error_callback = Closure::wrap(Box::new(move |err: JsValue| {
-        logging::error!("Geolocation Error or Timeout: {:?}", err);
-        // When this closure finishes, if success_callback hasn't run, 
-        // tx_success is dropped, which sends a 'Cancel' to rx.await.
-    }) as Box<dyn FnMut(JsValue)>);
-
-    let _ = geolocation.get_current_position_with_error_callback_and_options(
-        success_callback.as_ref().unchecked_ref(),
-        Some(error_callback.as_ref().unchecked_ref()),
-        &options // Pass the options here
-    );
-
-    // Prevent the browser from cleaning up the closures prematurely
-    success_callback.forget();
-    error_callback.forget();
-
-    // rx.await.ok() will now return None if:
-    // - The user denies permission
-    // - The 10-second timeout is reached
-    // - The device loses GPS signal
-    rx.await.ok()
-}
\ No newline at end of file
+    }
+}
@@ -0,0 +1,134 @@
+use leptos::prelude::document;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{CanvasRenderingContext2d, File, HtmlCanvasElement, HtmlImageElement, Url};
+
+/// Per image; a few seconds on a slow 3G connection
+pub const MAX_UPLOAD_BYTES: usize = 150 * 1024;
+
+// Tried in order until the JPEG fits the budget
+const JPEG_QUALITIES: [f64; 4] = [0.85, 0.75, 0.6, 0.45];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageKind {
+    Photo,
+    Logo,
+}
+
+impl ImageKind {
+    /// Also the last segment of the upload URL
+    pub fn path(self) -> &'static str {
+        match self {
+            ImageKind::Photo => "photo",
+            ImageKind::Logo => "logo",
+        }
+    }
+
+    /// Largest size we send, in pixels. Photos are 4:3 like the Home cards.
+    pub fn size(self) -> (u32, u32) {
+        match self {
+            ImageKind::Photo => (960, 720),
+            ImageKind::Logo => (256, 256),
+        }
+    }
+}
+
+/// The `(x, y, width, height)` of `src` to keep when cropping it to `aspect`
+/// (width / height). `focus` runs from 0 to 1 along whichever side is too long,
+/// 0.5 keeping the middle.
+pub fn crop_rect(src_w: f64, src_h: f64, aspect: f64, focus: f64) -> (f64, f64, f64, f64) {
+    let focus = focus.clamp(0.0, 1.0);
+    if src_w / src_h > aspect {
+        let w = src_h * aspect;
+        ((src_w - w) * focus, 0.0, w, src_h)
+    } else {
+        let h = src_w / aspect;
+        (0.0, (src_h - h) * focus, src_w, h)
+    }
+}
+
+/// What a base64 `data:` URL decodes to, in bytes
+pub fn data_url_bytes(data_url: &str) -> usize {
+    let data = data_url.split_once(',').map_or("", |(_, data)| data);
+    let padding = data.chars().rev().take_while(|c| *c == '=').count();
+    (data.len() / 4 * 3).saturating_sub(padding)
+}
+
+/// Decodes a picked file so it can be drawn
+pub async fn load_image(file: &File) -> Result<HtmlImageElement, String> {
+    let url = Url::create_object_url_with_blob(file).map_err(|e| format!("{:?}", e))?;
+    let img = HtmlImageElement::new().map_err(|e| format!("{:?}", e))?;
+    img.set_src(&url);
+    let decoded = JsFuture::from(img.decode()).await;
+    // Once decoded the element keeps its pixels, so the URL can go
+    let _ = Url::revoke_object_url(&url);
+    decoded.map_err(|e| format!("Not a readable image: {:?}", e))?;
+    Ok(img)
+}
+
+/// Crops and scales `img` for `kind` and encodes it as a JPEG `data:` URL,
+/// lowering the quality until it fits in `MAX_UPLOAD_BYTES`. Small pictures
+/// are never scaled up.
+pub fn resize(img: &HtmlImageElement, kind: ImageKind, focus: f64) -> Result<String, String> {
+    let (out_w, out_h) = kind.size();
+    let (out_w, out_h) = (f64::from(out_w), f64::from(out_h));
+    let (sx, sy, sw, sh) = crop_rect(f64::from(img.natural_width()), f64::from(img.natural_height()), out_w / out_h, focus);
+    let scale = (sw / out_w).min(1.0);
+    let (w, h) = ((out_w * scale).round().max(1.0), (out_h * scale).round().max(1.0));
+
+    let canvas: HtmlCanvasElement = document()
+        .create_element("canvas")
+        .map_err(|e| format!("{:?}", e))?
+        .unchecked_into();
+    canvas.set_width(w as u32);
+    canvas.set_height(h as u32);
+    let ctx: CanvasRenderingContext2d = canvas
+        .get_context("2d")
+        .map_err(|e| format!("{:?}", e))?
+        .ok_or("No 2D canvas")?
+        .unchecked_into();
+
+    // JPEG has no transparency; white is what logos usually sit on
+    ctx.set_fill_style_str("#fff");
+    ctx.fill_rect(0.0, 0.0, w, h);
+    ctx.draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(img, sx, sy, sw, sh, 0.0, 0.0, w, h)
+        .map_err(|e| format!("{:?}", e))?;
+
+    let mut data_url = String::new();
+    for quality in JPEG_QUALITIES {
+        data_url = canvas
+            .to_data_url_with_type_and_encoder_options("image/jpeg", &JsValue::from_f64(quality))
+            .map_err(|e| format!("{:?}", e))?;
+        if data_url_bytes(&data_url) <= MAX_UPLOAD_BYTES {
+            break;
+        }
+    }
+    Ok(data_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crops_wide_pictures_from_the_sides() {
+        // 16:9 phone shot cropped to 4:3
+        assert_eq!(crop_rect(1600.0, 900.0, 4.0 / 3.0, 0.5), (200.0, 0.0, 1200.0, 900.0));
+        assert_eq!(crop_rect(1600.0, 900.0, 4.0 / 3.0, 0.0), (0.0, 0.0, 1200.0, 900.0));
+        assert_eq!(crop_rect(1600.0, 900.0, 4.0 / 3.0, 1.0), (400.0, 0.0, 1200.0, 900.0));
+    }
+
+    #[test]
+    fn crops_tall_pictures_from_top_and_bottom() {
+        assert_eq!(crop_rect(900.0, 1600.0, 1.0, 0.5), (0.0, 350.0, 900.0, 900.0));
+        assert_eq!(crop_rect(900.0, 1600.0, 1.0, 7.0), (0.0, 700.0, 900.0, 900.0));
+    }
+
+    #[test]
+    fn counts_decoded_bytes() {
+        assert_eq!(data_url_bytes("data:image/jpeg;base64,QUJD"), 3);
+        assert_eq!(data_url_bytes("data:image/jpeg;base64,QUI="), 2);
+        assert_eq!(data_url_bytes("data:image/jpeg;base64,QQ=="), 1);
+        assert_eq!(data_url_bytes("not a data url"), 0);
+    }
+}
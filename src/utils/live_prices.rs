@@ -0,0 +1,85 @@
+use codee::string::JsonSerdeCodec;
+use leptos::prelude::*;
+use leptos_use::core::ConnectionReadyState;
+use leptos_use::{ReconnectLimit, UseEventSourceOptions, UseEventSourceReturn, use_event_source_with_options};
+use serde::{Deserialize, Serialize};
+
+use crate::pages::fetch_nearest_stations_dto::{ChangeAuthor, Station};
+use crate::utils::app_config::AppConfig;
+use crate::utils::base_url::BaseUrl;
+use crate::utils::money::Money;
+
+// The browser already retries dropped EventSource connections; this covers the
+// cases it gives up on (server restarts, 5xx) without hammering the API.
+const RECONNECT_INTERVAL_MS: u64 = 5000;
+
+/// One commodity's new price/availability, as pushed by the server.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommodityChange {
+    pub commodity_id: String,
+    pub station_id: String,
+    pub price: Money,
+    pub is_available: bool,
+    #[serde(default)]
+    pub updated_by: Option<ChangeAuthor>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl CommodityChange {
+    /// Patches the matching commodity, returns whether anything changed.
+    pub fn apply_to(&self, station: &mut Station) -> bool {
+        if station.id != self.station_id {
+            return false;
+        }
+        match station.commodities.iter_mut().find(|c| c.id == self.commodity_id) {
+            Some(c) if c.price != self.price || c.is_available != self.is_available => {
+                c.price = self.price;
+                c.is_available = self.is_available;
+                c.updated_by = self.updated_by.clone();
+                c.updated_at = self.updated_at.clone();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn apply_to_all(&self, stations: &mut [Station]) -> bool {
+        let mut changed = false;
+        for station in stations.iter_mut() {
+            changed |= self.apply_to(station);
+        }
+        changed
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct LivePrices {
+    pub change: Signal<Option<CommodityChange>>,
+    pub is_live: Signal<bool>,
+    pub enabled: bool,
+}
+
+/// Subscribes to `/api/v1/commodities/events` for as long as the calling component lives.
+/// Does nothing when the `live_prices` feature is off in `config.json`.
+pub fn use_live_prices() -> LivePrices {
+    let enabled = AppConfig::current().is_enabled("live_prices");
+    let url = if enabled {
+        format!("{}/api/v1/commodities/events", BaseUrl::get_base_url())
+    } else {
+        String::new()
+    };
+
+    let UseEventSourceReturn { message, ready_state, .. } = use_event_source_with_options::<CommodityChange, JsonSerdeCodec>(
+        url,
+        UseEventSourceOptions::default()
+            .reconnect_limit(ReconnectLimit::Infinite)
+            .reconnect_interval(RECONNECT_INTERVAL_MS),
+    );
+
+    LivePrices {
+        change: Signal::derive(move || message.get().map(|m| m.data)),
+        is_live: Signal::derive(move || ready_state.get() == ConnectionReadyState::Open),
+        enabled,
+    }
+}
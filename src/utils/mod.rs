@@ -2,4 +2,15 @@ pub mod get_stations_imgs;
 pub mod get_gps_location;
 pub mod protect_route;
 pub mod base_url;
-pub mod validate_boundary;
\ No newline at end of file
+pub mod validate_boundary;
+pub mod app_config;
+pub mod money;
+pub mod live_prices;
+pub mod session;
+pub mod api;
+pub mod countdown;
+pub mod validation;
+pub mod form;
+pub mod qr;
+pub mod web_mercator;
+pub mod image_resize;
\ No newline at end of file
@@ -0,0 +1,206 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::i18n::{Locale, Msg};
+
+const KOBO_PER_NAIRA: i64 = 100;
+
+/// Anything above this per litre/kg is a typo (an extra zero), not a price.
+const MAX_PRICE_NAIRA: i64 = 100_000;
+
+/// A naira amount held as whole kobo so prices never go through float rounding.
+/// On the wire it is a plain naira number (`650` or `1250.5`), as the API expects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money {
+    kobo: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoneyError {
+    Empty,
+    Invalid,
+    Negative,
+    TooManyDecimals,
+    TooLarge,
+}
+
+impl MoneyError {
+    pub fn msg(self) -> Msg {
+        match self {
+            MoneyError::Empty => Msg::PriceRequired,
+            MoneyError::Invalid => Msg::PriceInvalid,
+            MoneyError::Negative => Msg::PriceNegative,
+            MoneyError::TooManyDecimals => Msg::PriceTooPrecise,
+            MoneyError::TooLarge => Msg::PriceTooLarge,
+        }
+    }
+}
+
+impl Money {
+    pub const fn from_kobo(kobo: i64) -> Self {
+        Self { kobo }
+    }
+
+    pub const fn from_naira(naira: i64) -> Self {
+        Self { kobo: naira * KOBO_PER_NAIRA }
+    }
+
+    pub fn kobo(self) -> i64 {
+        self.kobo
+    }
+
+    pub fn naira(self) -> i64 {
+        self.kobo / KOBO_PER_NAIRA
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.kobo == 0
+    }
+
+    pub fn is_positive(self) -> bool {
+        self.kobo > 0
+    }
+
+    /// Parses what an owner types into a price box: "650", "1,250.50", "₦1250.5".
+    pub fn parse(input: &str) -> Result<Self, MoneyError> {
+        let cleaned: String = input
+            .trim()
+            .trim_start_matches('₦')
+            .chars()
+            .filter(|c| *c != ',' && !c.is_whitespace())
+            .collect();
+
+        if cleaned.is_empty() {
+            return Err(MoneyError::Empty);
+        }
+        if cleaned.starts_with('-') {
+            return Err(MoneyError::Negative);
+        }
+
+        let (whole, frac) = cleaned.split_once('.').unwrap_or((&cleaned, ""));
+        let digits_only = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && frac.is_empty()) || !digits_only(whole) || !digits_only(frac) {
+            return Err(MoneyError::Invalid);
+        }
+        if frac.len() > 2 {
+            return Err(MoneyError::TooManyDecimals);
+        }
+        // Anything this long is past the cap anyway, and it keeps the parse below from overflowing
+        if whole.len() > 12 {
+            return Err(MoneyError::TooLarge);
+        }
+
+        let naira: i64 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| MoneyError::Invalid)? };
+        let kobo: i64 = format!("{frac:0<2}").parse().map_err(|_| MoneyError::Invalid)?;
+        let money = Money::from_kobo(naira * KOBO_PER_NAIRA + kobo);
+
+        if money > Money::from_naira(MAX_PRICE_NAIRA) {
+            return Err(MoneyError::TooLarge);
+        }
+        Ok(money)
+    }
+
+    /// "1250.50" style, for prefilling an input without the currency sign
+    pub fn to_input_string(self) -> String {
+        if self.kobo % KOBO_PER_NAIRA == 0 {
+            self.naira().to_string()
+        } else {
+            format!("{}.{:02}", self.naira(), (self.kobo % KOBO_PER_NAIRA).abs())
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&Locale::En.format_naira(*self))
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.kobo % KOBO_PER_NAIRA == 0 {
+            serializer.serialize_i64(self.naira())
+        } else {
+            serializer.serialize_f64(self.kobo as f64 / KOBO_PER_NAIRA as f64)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Int(i64),
+            Float(f64),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Int(naira) => naira
+                .checked_mul(KOBO_PER_NAIRA)
+                .map(Money::from_kobo)
+                .ok_or_else(|| serde::de::Error::custom(format!("price {naira} is out of range"))),
+            Raw::Float(naira) => Ok(Money::from_kobo((naira * KOBO_PER_NAIRA as f64).round() as i64)),
+            Raw::Text(text) => Money::parse(&text)
+                .map_err(|e| serde::de::Error::custom(format!("invalid price {text:?}: {e:?}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_what_owners_type() {
+        assert_eq!(Money::parse("650"), Ok(Money::from_naira(650)));
+        assert_eq!(Money::parse(" 1,250.50 "), Ok(Money::from_kobo(125_050)));
+        assert_eq!(Money::parse("₦1250.5"), Ok(Money::from_kobo(125_050)));
+        assert_eq!(Money::parse(".5"), Ok(Money::from_kobo(50)));
+        assert_eq!(Money::parse("0"), Ok(Money::from_kobo(0)));
+    }
+
+    #[test]
+    fn rejects_bad_prices() {
+        assert_eq!(Money::parse(""), Err(MoneyError::Empty));
+        assert_eq!(Money::parse("₦"), Err(MoneyError::Empty));
+        assert_eq!(Money::parse("-650"), Err(MoneyError::Negative));
+        assert_eq!(Money::parse("650.125"), Err(MoneyError::TooManyDecimals));
+        assert_eq!(Money::parse("."), Err(MoneyError::Invalid));
+        assert_eq!(Money::parse("6a0"), Err(MoneyError::Invalid));
+        assert_eq!(Money::parse("1.2.3"), Err(MoneyError::Invalid));
+    }
+
+    #[test]
+    fn caps_prices() {
+        assert_eq!(Money::parse("100000"), Ok(Money::from_naira(MAX_PRICE_NAIRA)));
+        assert_eq!(Money::parse("100000.01"), Err(MoneyError::TooLarge));
+        assert_eq!(Money::parse(&"9".repeat(40)), Err(MoneyError::TooLarge));
+    }
+
+    #[test]
+    fn round_trips_input_strings() {
+        assert_eq!(Money::from_naira(650).to_input_string(), "650");
+        assert_eq!(Money::from_kobo(125_050).to_input_string(), "1250.50");
+        assert_eq!(Money::from_kobo(5).to_input_string(), "0.05");
+    }
+
+    #[test]
+    fn deserializes_ints_floats_and_text() {
+        let parse = |json: &str| serde_json::from_str::<Money>(json);
+        assert_eq!(parse("650").unwrap(), Money::from_naira(650));
+        assert_eq!(parse("1250.5").unwrap(), Money::from_kobo(125_050));
+        assert_eq!(parse("0.1").unwrap(), Money::from_kobo(10));
+        assert_eq!(parse(r#""1,250.50""#).unwrap(), Money::from_kobo(125_050));
+        assert!(parse(r#""abc""#).is_err());
+        assert!(parse(&i64::MAX.to_string()).is_err());
+    }
+
+    #[test]
+    fn serializes_as_naira() {
+        assert_eq!(serde_json::to_string(&Money::from_naira(650)).unwrap(), "650");
+        assert_eq!(serde_json::to_string(&Money::from_kobo(125_050)).unwrap(), "1250.5");
+    }
+}
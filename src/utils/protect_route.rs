@@ -1,8 +1,24 @@
 use leptos::prelude::*;
-use leptos_router::hooks::use_navigate;
 
+use crate::utils::session::{SessionContext, load_session};
+
+/// Signed in with a token that hasn't expired yet
 pub fn is_authenticated() -> bool {
-    window().local_storage().ok().flatten()
-        .and_then(|s| s.get_item("accessToken").ok().flatten())
-        .is_some()
-}
\ No newline at end of file
+    match use_context::<SessionContext>() {
+        Some(session) => session.is_signed_in(),
+        None => load_session().is_some(),
+    }
+}
+
+/// `/signin?next=<path>` so the user comes back to where they were headed
+pub fn signin_redirect(return_to: &str) -> String {
+    let next: String = js_sys::encode_uri_component(return_to).into();
+    format!("/signin?next={next}")
+}
+
+/// Only same-origin paths are followed, so a crafted link can't bounce a
+/// freshly signed-in owner to another site. Without one we go to `fallback`.
+pub fn safe_next(next: Option<String>, fallback: &str) -> String {
+    next.filter(|n| n.starts_with('/') && !n.starts_with("//") && !n.starts_with("/\\"))
+        .unwrap_or_else(|| fallback.to_string())
+}
@@ -0,0 +1,13 @@
+use qrcode::QrCode;
+use qrcode::render::svg;
+
+/// Renders `data` as an SVG QR code. Done in the browser so the TOTP secret
+/// never goes to a third-party QR service.
+pub fn qr_svg(data: &str) -> Result<String, String> {
+    let code = QrCode::new(data.as_bytes()).map_err(|e| format!("Could not make QR code: {}", e))?;
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(200, 200)
+        .quiet_zone(true)
+        .build())
+}
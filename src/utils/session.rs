@@ -0,0 +1,372 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::api::refresh_tokens;
+
+pub const TOKEN_KEY: &str = "accessToken";
+pub const REFRESH_TOKEN_KEY: &str = "refreshToken";
+
+/// Roles allowed into `/admin`
+pub const ADMIN_ROLES: &[&str] = &["admin", "super_admin"];
+/// The account that registered the station
+pub const OWNER_ROLE: &str = "station";
+/// Attendants invited by an owner; they can only do what their permissions allow
+pub const STAFF_ROLE: &str = "staff";
+
+/// What an owner can let a staff account do. Staff with none of these are view only.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    UpdatePrices,
+    ToggleAvailability,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 2] = [Permission::UpdatePrices, Permission::ToggleAvailability];
+
+    fn from_code(code: &str) -> Option<Permission> {
+        match code {
+            "update_prices" => Some(Permission::UpdatePrices),
+            "toggle_availability" => Some(Permission::ToggleAvailability),
+            _ => None,
+        }
+    }
+}
+
+/// Treat tokens as expired a little early so a request sent right before
+/// expiry doesn't reach the API with a dead token.
+const EXPIRY_LEEWAY_SECS: i64 = 30;
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    station_id: Option<String>,
+    #[serde(default)]
+    role: String,
+    /// Only on staff tokens. Kept as strings so a permission this build doesn't
+    /// know about yet is ignored instead of failing the whole token.
+    #[serde(default)]
+    permissions: Vec<String>,
+    exp: i64,
+}
+
+/// Who is signed in, read from the access token's claims
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    pub token: String,
+    pub refresh_token: Option<String>,
+    pub station_id: String,
+    pub role: String,
+    pub permissions: Vec<Permission>,
+    /// Unix seconds
+    pub expires_at: i64,
+}
+
+impl Session {
+    /// Decodes the JWT payload. The signature isn't checked here, that's the API's job;
+    /// we only need to know who the token is for and when it stops working.
+    pub fn from_token(token: &str, refresh_token: Option<String>) -> Result<Self, String> {
+        let payload = token.split('.').nth(1).ok_or("Malformed token")?;
+        let bytes = URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|e| format!("Malformed token: {}", e))?;
+        let claims: Claims = serde_json::from_slice(&bytes).map_err(|e| format!("Malformed token claims: {}", e))?;
+
+        Ok(Self {
+            token: token.to_string(),
+            refresh_token,
+            station_id: claims.station_id.unwrap_or(claims.sub),
+            role: claims.role,
+            permissions: claims.permissions.iter().filter_map(|p| Permission::from_code(p)).collect(),
+            expires_at: claims.exp,
+        })
+    }
+
+    pub fn expires_in_secs(&self, now: i64) -> i64 {
+        self.expires_at - EXPIRY_LEEWAY_SECS - now
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_in_secs(now_secs()) <= 0
+    }
+
+    /// An expired access token is still fine while we hold a refresh token for it
+    pub fn is_usable(&self) -> bool {
+        !self.is_expired() || self.refresh_token.is_some()
+    }
+
+    pub fn has_any_role(&self, roles: &[&str]) -> bool {
+        roles.iter().any(|r| r.eq_ignore_ascii_case(&self.role))
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.has_any_role(ADMIN_ROLES)
+    }
+
+    pub fn is_staff(&self) -> bool {
+        self.role.eq_ignore_ascii_case(STAFF_ROLE)
+    }
+
+    pub fn is_owner(&self) -> bool {
+        self.role.eq_ignore_ascii_case(OWNER_ROLE)
+    }
+
+    /// Owners can do everything on their station; staff only what they were given.
+    /// Any other role, including a missing one, can do nothing.
+    pub fn can(&self, permission: Permission) -> bool {
+        self.is_owner() || (self.is_staff() && self.permissions.contains(&permission))
+    }
+
+    /// Where this user lands after signing in
+    pub fn home_path(&self) -> &'static str {
+        if self.is_admin() { "/admin" } else { "/station" }
+    }
+}
+
+pub fn now_secs() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// The stored session if it's still usable. Dead or unreadable tokens are cleared.
+pub fn load_session() -> Option<Session> {
+    let storage = storage()?;
+    let token = storage.get_item(TOKEN_KEY).ok().flatten()?;
+    let refresh_token = storage.get_item(REFRESH_TOKEN_KEY).ok().flatten();
+    match Session::from_token(&token, refresh_token) {
+        Ok(session) if session.is_usable() => Some(session),
+        _ => {
+            clear_stored_session();
+            None
+        }
+    }
+}
+
+fn clear_stored_session() {
+    if let Some(s) = storage() {
+        let _ = s.remove_item(TOKEN_KEY);
+        let _ = s.remove_item(REFRESH_TOKEN_KEY);
+    }
+}
+
+type PendingRefresh = Shared<LocalBoxFuture<'static, Result<Session, String>>>;
+
+#[derive(Clone, Copy)]
+pub struct SessionContext {
+    session: RwSignal<Option<Session>>,
+    /// The refresh currently on the wire, if any. Refresh tokens rotate, so
+    /// concurrent 401s must share one request instead of each spending the token.
+    refreshing: StoredValue<Option<PendingRefresh>, LocalStorage>,
+}
+
+impl SessionContext {
+    pub fn get(&self) -> Option<Session> {
+        self.session.get()
+    }
+
+    pub fn get_untracked(&self) -> Option<Session> {
+        self.session.get_untracked()
+    }
+
+    /// Reactive; reads the session without cloning it
+    pub fn with<R>(&self, f: impl FnOnce(&Option<Session>) -> R) -> R {
+        self.session.with(f)
+    }
+
+    pub fn is_signed_in(&self) -> bool {
+        self.session.with_untracked(|s| s.as_ref().is_some_and(Session::is_usable))
+    }
+
+    /// Reactive, for showing admin-only links
+    pub fn is_admin(&self) -> bool {
+        self.session.with(|s| s.as_ref().is_some_and(Session::is_admin))
+    }
+
+    /// Reactive
+    pub fn can(&self, permission: Permission) -> bool {
+        self.session.with(|s| s.as_ref().is_some_and(|s| s.can(permission)))
+    }
+
+    /// Reactive; the station owner rather than one of their staff
+    pub fn is_owner(&self) -> bool {
+        self.session.with(|s| s.as_ref().is_some_and(Session::is_owner))
+    }
+
+    /// The access token as currently stored, without refreshing
+    pub fn token_untracked(&self) -> Option<String> {
+        self.session.with_untracked(|s| s.as_ref().map(|s| s.token.clone()))
+    }
+
+    /// Stores the tokens and makes them the current session
+    pub fn sign_in(&self, token: &str, refresh_token: Option<&str>) -> Result<Session, String> {
+        let session = Session::from_token(token, refresh_token.map(str::to_string))?;
+        if session.is_expired() {
+            return Err("Session has already expired, please sign in again".into());
+        }
+
+        let s = storage().ok_or("Local storage is not available")?;
+        s.set_item(TOKEN_KEY, token).map_err(|_| "Could not save session")?;
+        match &session.refresh_token {
+            Some(refresh) => s.set_item(REFRESH_TOKEN_KEY, refresh).map_err(|_| "Could not save session")?,
+            None => { let _ = s.remove_item(REFRESH_TOKEN_KEY); }
+        }
+        self.session.set(Some(session.clone()));
+        Ok(session)
+    }
+
+    /// Swaps the refresh token for a new access token. Any failure ends the session.
+    /// Calls made while a refresh is running wait for that one.
+    pub async fn refresh(&self) -> Result<Session, String> {
+        let pending = match self.refreshing.get_value() {
+            Some(pending) => pending,
+            None => {
+                let ctx = *self;
+                let pending = async move {
+                    let result = ctx.refresh_once().await;
+                    ctx.refreshing.set_value(None);
+                    result
+                }
+                .boxed_local()
+                .shared();
+                self.refreshing.set_value(Some(pending.clone()));
+                pending
+            }
+        };
+        pending.await
+    }
+
+    async fn refresh_once(&self) -> Result<Session, String> {
+        let before = self.session.get_untracked().ok_or("Session expired, please sign in again")?;
+        let refresh_token = before.refresh_token.clone().ok_or("Session expired, please sign in again")?;
+
+        // Endpoints that don't rotate the refresh token leave the one we have in place
+        let result = match refresh_tokens(&refresh_token).await {
+            Ok(tokens) => self.sign_in(
+                &tokens.access_token,
+                Some(tokens.refresh_token.as_deref().unwrap_or(&refresh_token)),
+            ),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(session) => Ok(session),
+            Err(e) => {
+                // Two calls can 401 at once; if the other one already refreshed, use its token
+                if let Some(current) = self.session.get_untracked() {
+                    if current.token != before.token && !current.is_expired() {
+                        return Ok(current);
+                    }
+                }
+                leptos::logging::warn!("Token refresh failed: {}", e);
+                self.sign_out();
+                Err("Session expired, please sign in again".into())
+            }
+        }
+    }
+
+    /// A token that is good to send right now, refreshing first if it has expired
+    pub async fn valid_token(&self) -> Result<String, String> {
+        let session = self.session.get_untracked().ok_or("You are not signed in")?;
+        if session.is_expired() {
+            Ok(self.refresh().await?.token)
+        } else {
+            Ok(session.token)
+        }
+    }
+
+    pub fn sign_out(&self) {
+        clear_stored_session();
+        self.session.set(None);
+    }
+}
+
+pub fn provide_session() -> SessionContext {
+    let ctx = SessionContext {
+        session: RwSignal::new(load_session()),
+        refreshing: StoredValue::new_local(None),
+    };
+    provide_context(ctx);
+    ctx
+}
+
+pub fn use_session() -> SessionContext {
+    expect_context::<SessionContext>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(claims: &str) -> String {
+        format!("{}.{}.signature", URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256"}"#), URL_SAFE_NO_PAD.encode(claims))
+    }
+
+    #[test]
+    fn reads_claims_from_the_payload() {
+        let jwt = token(r#"{"sub":"42","role":"staff","permissions":["update_prices","launch_rockets"],"exp":1000}"#);
+        let session = Session::from_token(&jwt, Some("r".into())).unwrap();
+        assert_eq!(session.token, jwt);
+        assert_eq!(session.refresh_token.as_deref(), Some("r"));
+        assert_eq!(session.station_id, "42");
+        assert_eq!(session.role, "staff");
+        assert_eq!(session.permissions, vec![Permission::UpdatePrices]);
+        assert_eq!(session.expires_at, 1000);
+    }
+
+    #[test]
+    fn prefers_station_id_over_sub() {
+        let session = Session::from_token(&token(r#"{"sub":"7","station_id":"s-1","exp":0}"#), None).unwrap();
+        assert_eq!(session.station_id, "s-1");
+        assert_eq!(session.role, "");
+    }
+
+    #[test]
+    fn expires_early_by_the_leeway() {
+        let session = Session::from_token(&token(r#"{"sub":"1","exp":1000}"#), None).unwrap();
+        assert_eq!(session.expires_in_secs(1000 - EXPIRY_LEEWAY_SECS - 1), 1);
+        assert_eq!(session.expires_in_secs(1000 - EXPIRY_LEEWAY_SECS), 0);
+        assert!(session.expires_in_secs(1000) < 0);
+    }
+
+    #[test]
+    fn only_owners_and_permitted_staff_can_act() {
+        let with_role = |role: &str, permissions: &str| {
+            let claims = format!(r#"{{"sub":"1","role":"{role}","permissions":[{permissions}],"exp":0}}"#);
+            Session::from_token(&token(&claims), None).unwrap()
+        };
+        let owner = with_role("Station", "");
+        assert!(owner.is_owner());
+        assert!(Permission::ALL.iter().all(|p| owner.can(*p)));
+
+        let staff = with_role("staff", r#""toggle_availability""#);
+        assert!(!staff.is_owner());
+        assert!(staff.can(Permission::ToggleAvailability));
+        assert!(!staff.can(Permission::UpdatePrices));
+
+        for role in ["", "admin", "manager"] {
+            let other = with_role(role, r#""update_prices""#);
+            assert!(!other.is_owner(), "{role}");
+            assert!(Permission::ALL.iter().all(|p| !other.can(*p)), "{role}");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_tokens() {
+        for jwt in [
+            "".to_string(),
+            "no-dots".to_string(),
+            "header.!!!.sig".to_string(),
+            token("not json"),
+            token(r#"{"sub":"1"}"#),
+        ] {
+            assert!(Session::from_token(&jwt, None).is_err(), "{jwt}");
+        }
+    }
+}
@@ -1,15 +1,230 @@
 use leptos::logging;
 
-pub fn validate_abuja_bounds(lat: f64, lon: f64) -> Result<(), String> {
-    let min_lat = 8.25;
-    let max_lat = 9.30;
-    let min_lon = 6.75;
-    let max_lon = 7.75;
-
-    logging::log!("ran here in val_boundary 7");
-    if lat >= min_lat && lat <= max_lat && lon >= min_lon && lon <= max_lon {
-        Ok(())
-    } else {
-        Err("Seems like you are outside the Abuja service area.".into())
-    }
-}
\ No newline at end of file
+use crate::i18n::Msg;
+
+/// Mean radius of the earth, used to turn degree offsets into kilometres.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// A region we serve, described by its boundary polygon as `(lat, lon)` vertices.
+/// `buffer_km` lets fixes that land just outside the line (GPS drift, stations
+/// sitting on the border road) through without opening up the neighbouring state.
+pub struct ServiceArea {
+    pub name: &'static str,
+    pub boundary: &'static [(f64, f64)],
+    pub buffer_km: f64,
+}
+
+/// Federal Capital Territory boundary, traced clockwise from the northern tip.
+/// Keeps Suleja/Madalla (Niger) and Mararaba/Keffi (Nasarawa) outside.
+pub const ABUJA: ServiceArea = ServiceArea {
+    name: "Abuja",
+    boundary: &[
+        (9.40, 7.33),
+        (9.38, 7.45),
+        (9.25, 7.55),
+        (9.10, 7.58),
+        (9.02, 7.575),
+        (8.90, 7.60),
+        (8.75, 7.55),
+        (8.55, 7.40),
+        (8.40, 7.20),
+        (8.35, 6.98),
+        (8.45, 6.80),
+        (8.65, 6.78),
+        (8.85, 6.90),
+        (9.00, 7.05),
+        (9.10, 7.20),
+        (9.22, 7.24),
+        (9.33, 7.26),
+    ],
+    buffer_km: 0.5,
+};
+
+impl ServiceArea {
+    pub fn contains(&self, lat: f64, lon: f64) -> bool {
+        is_within_boundary(lat, lon, self.boundary, self.buffer_km)
+    }
+}
+
+/// Ray casting test. Points exactly on an edge may land on either side, so
+/// callers that care about the border should go through `is_within_boundary`.
+pub fn point_in_polygon(lat: f64, lon: f64, polygon: &[(f64, f64)]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for i in 0..polygon.len() {
+        let (lat_i, lon_i) = polygon[i];
+        let (lat_j, lon_j) = polygon[j];
+        if (lat_i > lat) != (lat_j > lat)
+            && lon < (lon_j - lon_i) * (lat - lat_i) / (lat_j - lat_i) + lon_i
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Shortest distance in km from the point to any edge of the polygon.
+pub fn distance_to_polygon_km(lat: f64, lon: f64, polygon: &[(f64, f64)]) -> f64 {
+    let mut closest = f64::INFINITY;
+    let mut j = polygon.len().wrapping_sub(1);
+    for i in 0..polygon.len() {
+        closest = closest.min(distance_to_segment_km((lat, lon), polygon[j], polygon[i]));
+        j = i;
+    }
+    closest
+}
+
+/// Inside the polygon, on its edge, or no further than `buffer_km` outside it.
+pub fn is_within_boundary(lat: f64, lon: f64, polygon: &[(f64, f64)], buffer_km: f64) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    if point_in_polygon(lat, lon, polygon) {
+        return true;
+    }
+    // A tiny tolerance so points sitting exactly on the line count as inside
+    distance_to_polygon_km(lat, lon, polygon) <= buffer_km.max(0.0) + 1e-6
+}
+
+// Projects onto a local flat plane around `p`; fine at the scale of a state border.
+fn distance_to_segment_km(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let km_per_deg = EARTH_RADIUS_KM.to_radians();
+    let cos_lat = p.0.to_radians().cos();
+    let to_xy = |(lat, lon): (f64, f64)| ((lon - p.1) * km_per_deg * cos_lat, (lat - p.0) * km_per_deg);
+
+    let (ax, ay) = to_xy(a);
+    let (bx, by) = to_xy(b);
+    let (dx, dy) = (bx - ax, by - ay);
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq == 0.0 { 0.0 } else { (-(ax * dx + ay * dy) / len_sq).clamp(0.0, 1.0) };
+    let (cx, cy) = (ax + t * dx, ay + t * dy);
+    (cx * cx + cy * cy).sqrt()
+}
+
+/// Every region we have a boundary for. `config.json` decides which are live.
+pub const SERVICE_AREAS: &[ServiceArea] = &[ABUJA];
+
+pub fn enabled_areas(enabled_regions: &[String]) -> Vec<&'static ServiceArea> {
+    SERVICE_AREAS
+        .iter()
+        .filter(|area| enabled_regions.iter().any(|r| r.eq_ignore_ascii_case(area.name)))
+        .collect()
+}
+
+/// Why a position can't be served
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AreaError {
+    /// `regions` lists the enabled areas, for the message
+    Outside { regions: String },
+    NoneEnabled,
+}
+
+impl AreaError {
+    pub fn msg(&self) -> Msg {
+        match self {
+            AreaError::Outside { .. } => Msg::OutsideServiceArea,
+            AreaError::NoneEnabled => Msg::NoServiceArea,
+        }
+    }
+
+    /// `translated` is `msg()` in the user's language; fills in the area names
+    pub fn describe(&self, translated: &str) -> String {
+        match self {
+            AreaError::Outside { regions } => translated.replace("{regions}", regions),
+            AreaError::NoneEnabled => translated.to_string(),
+        }
+    }
+}
+
+pub fn validate_service_area(lat: f64, lon: f64, enabled_regions: &[String]) -> Result<(), AreaError> {
+    let enabled = enabled_areas(enabled_regions);
+
+    if enabled.iter().any(|area| area.contains(lat, lon)) {
+        return Ok(());
+    }
+
+    logging::log!("{}, {} is outside every enabled service area", lat, lon);
+    if enabled.is_empty() {
+        return Err(AreaError::NoneEnabled);
+    }
+    let regions = enabled.iter().map(|area| area.name).collect::<Vec<_>>().join(", ");
+    Err(AreaError::Outside { regions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abuja_only() -> Vec<String> {
+        vec!["abuja".to_string()]
+    }
+
+    const SQUARE: &[(f64, f64)] = &[(9.0, 7.0), (9.0, 7.1), (9.1, 7.1), (9.1, 7.0)];
+
+    #[test]
+    fn accepts_points_inside_the_fct() {
+        // Central Area, Gwagwalada, Kuje, Bwari, Nyanya
+        for (lat, lon) in [(9.0579, 7.4951), (8.9436, 7.0836), (8.8792, 7.2275), (9.2833, 7.3833), (9.0400, 7.5580)] {
+            assert!(validate_service_area(lat, lon, &abuja_only()).is_ok(), "{lat}, {lon} should be inside");
+        }
+    }
+
+    #[test]
+    fn rejects_neighbouring_towns_the_rectangle_let_through() {
+        // Mararaba and Keffi (Nasarawa), Suleja (Niger)
+        for (lat, lon) in [(9.0330, 7.5960), (8.8486, 7.8736), (9.1806, 7.1794)] {
+            assert!(validate_service_area(lat, lon, &abuja_only()).is_err(), "{lat}, {lon} should be outside");
+        }
+    }
+
+    #[test]
+    fn rejects_points_far_away() {
+        assert!(validate_service_area(6.5244, 3.3792, &abuja_only()).is_err());
+        assert!(validate_service_area(7.8023, 6.7333, &abuja_only()).is_err());
+    }
+
+    #[test]
+    fn says_which_areas_are_served() {
+        let err = validate_service_area(6.5244, 3.3792, &abuja_only()).unwrap_err();
+        assert_eq!(err, AreaError::Outside { regions: "Abuja".to_string() });
+        assert_eq!(err.describe("outside {regions}"), "outside Abuja");
+    }
+
+    #[test]
+    fn disabled_regions_reject_everything() {
+        assert_eq!(validate_service_area(9.0579, 7.4951, &[]), Err(AreaError::NoneEnabled));
+        assert_eq!(validate_service_area(9.0579, 7.4951, &["lagos".to_string()]), Err(AreaError::NoneEnabled));
+        assert!(validate_service_area(9.0579, 7.4951, &["ABUJA".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn points_on_an_edge_or_vertex_are_inside() {
+        assert!(is_within_boundary(9.0, 7.05, SQUARE, 0.0));
+        assert!(is_within_boundary(9.05, 7.1, SQUARE, 0.0));
+        assert!(is_within_boundary(9.1, 7.1, SQUARE, 0.0));
+    }
+
+    #[test]
+    fn buffer_only_admits_points_close_to_the_border() {
+        // ~330m east of the square's eastern edge
+        let (lat, lon) = (9.05, 7.103);
+        assert!(!is_within_boundary(lat, lon, SQUARE, 0.0));
+        assert!(is_within_boundary(lat, lon, SQUARE, 0.5));
+        // ~2.2km east stays out
+        assert!(!is_within_boundary(9.05, 7.12, SQUARE, 0.5));
+    }
+
+    #[test]
+    fn distance_is_zero_inside_edge_and_grows_outside() {
+        assert!(distance_to_polygon_km(9.0, 7.05, SQUARE) < 1e-9);
+        let d = distance_to_polygon_km(9.05, 7.2, SQUARE);
+        assert!((d - 10.99).abs() < 0.1, "got {d}");
+    }
+
+    #[test]
+    fn degenerate_polygons_contain_nothing() {
+        assert!(!is_within_boundary(9.0, 7.0, &[], 1.0));
+        assert!(!is_within_boundary(9.0, 7.0, &[(9.0, 7.0), (9.1, 7.1)], 1.0));
+    }
+}
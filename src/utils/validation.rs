@@ -0,0 +1,321 @@
+use crate::i18n::Msg;
+
+const MIN_PASSWORD_LEN: usize = 8;
+// bcrypt only looks at the first 72 bytes; anything longer is silently truncated
+const MAX_PASSWORD_BYTES: usize = 72;
+
+/// Network prefixes (local format, leading zero) of Nigerian mobile lines.
+/// Landlines and anything not allocated to a mobile operator are rejected.
+const MOBILE_PREFIXES: &[&str] = &[
+    "0701", "0702", "0703", "0704", "0705", "0706", "0707", "0708", "0709",
+    "0802", "0803", "0804", "0805", "0806", "0807", "0808", "0809",
+    "0810", "0811", "0812", "0813", "0814", "0815", "0816", "0817", "0818", "0819",
+    "0901", "0902", "0903", "0904", "0905", "0906", "0907", "0908", "0909",
+    "0911", "0912", "0913", "0915", "0916",
+];
+
+/// Passwords that show up at the top of every breach list. Lowercased.
+const COMMON_PASSWORDS: &[&str] = &[
+    "password", "password1", "password123", "12345678", "123456789", "1234567890",
+    "qwerty123", "qwertyuiop", "11111111", "00000000", "iloveyou", "abc12345",
+    "letmein1", "welcome1", "admin123", "football", "passw0rd", "nigeria1",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    EmailRequired,
+    EmailInvalid,
+    PhoneRequired,
+    PhoneInvalid,
+    PhoneNotMobile,
+    PasswordRequired,
+    PasswordTooShort,
+    PasswordTooLong,
+    PasswordTooWeak,
+    OtpInvalid,
+    RecoveryCodeRequired,
+}
+
+impl ValidationError {
+    pub fn msg(self) -> Msg {
+        match self {
+            ValidationError::EmailRequired => Msg::EmailRequired,
+            ValidationError::EmailInvalid => Msg::EmailInvalid,
+            ValidationError::PhoneRequired => Msg::PhoneRequired,
+            ValidationError::PhoneInvalid => Msg::InvalidPhone,
+            ValidationError::PhoneNotMobile => Msg::PhoneNotMobile,
+            ValidationError::PasswordRequired => Msg::PasswordRequired,
+            ValidationError::PasswordTooShort => Msg::PasswordTooShort,
+            ValidationError::PasswordTooLong => Msg::PasswordTooLong,
+            ValidationError::PasswordTooWeak => Msg::PasswordTooWeak,
+            ValidationError::OtpInvalid => Msg::OtpInvalid,
+            ValidationError::RecoveryCodeRequired => Msg::RecoveryCodeRequired,
+        }
+    }
+}
+
+/// Checks the address is `local@domain.tld` shaped and returns it trimmed, with
+/// the domain lowercased. Deliverability is the verification email's job.
+pub fn validate_email(input: &str) -> Result<String, ValidationError> {
+    let email = input.trim();
+    if email.is_empty() {
+        return Err(ValidationError::EmailRequired);
+    }
+    if email.len() > 254 {
+        return Err(ValidationError::EmailInvalid);
+    }
+
+    let (local, domain) = email.rsplit_once('@').ok_or(ValidationError::EmailInvalid)?;
+    if !is_valid_local_part(local) || !is_valid_domain(domain) {
+        return Err(ValidationError::EmailInvalid);
+    }
+    Ok(format!("{local}@{}", domain.to_ascii_lowercase()))
+}
+
+fn is_valid_local_part(local: &str) -> bool {
+    const SPECIALS: &str = "!#$%&'*+/=?^_`{|}~.-";
+    !local.is_empty()
+        && local.len() <= 64
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local.chars().all(|c| c.is_ascii_alphanumeric() || SPECIALS.contains(c))
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return false;
+    }
+    let label_ok = |l: &&str| {
+        !l.is_empty()
+            && l.len() <= 63
+            && !l.starts_with('-')
+            && !l.ends_with('-')
+            && l.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    let tld = labels[labels.len() - 1];
+    labels.iter().all(label_ok) && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Accepts a Nigerian mobile number the way people write it ("0803 123 4567",
+/// "+234 803-123-4567", "2348031234567", "8031234567") and returns it as
+/// E.164: `+2348031234567`.
+pub fn normalize_phone(input: &str) -> Result<String, ValidationError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ValidationError::PhoneRequired);
+    }
+
+    let (has_plus, rest) = match trimmed.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed),
+    };
+    if !rest.chars().all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-' | '(' | ')' | '.')) {
+        return Err(ValidationError::PhoneInvalid);
+    }
+    let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+
+    // Down to the 10-digit national significant number
+    let national = match digits.strip_prefix("234") {
+        Some(n) if digits.len() == 13 => n.strip_prefix('0').unwrap_or(n).to_string(),
+        // "+234 0803..." — people often keep the trunk zero after the country code
+        _ if digits.len() == 14 && digits.starts_with("2340") => digits[4..].to_string(),
+        _ if has_plus => return Err(ValidationError::PhoneInvalid),
+        _ if digits.len() == 11 && digits.starts_with('0') => digits[1..].to_string(),
+        _ if digits.len() == 10 && !digits.starts_with('0') => digits,
+        _ => return Err(ValidationError::PhoneInvalid),
+    };
+    if national.len() != 10 {
+        return Err(ValidationError::PhoneInvalid);
+    }
+    if !MOBILE_PREFIXES.contains(&&format!("0{}", &national[..3])[..]) {
+        return Err(ValidationError::PhoneNotMobile);
+    }
+    Ok(format!("+234{national}"))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    pub fn msg(self) -> Msg {
+        match self {
+            PasswordStrength::Weak => Msg::PasswordWeak,
+            PasswordStrength::Fair => Msg::PasswordFair,
+            PasswordStrength::Good => Msg::PasswordGood,
+            PasswordStrength::Strong => Msg::PasswordStrong,
+        }
+    }
+
+    /// CSS modifier for the meter
+    pub fn class(self) -> &'static str {
+        match self {
+            PasswordStrength::Weak => "weak",
+            PasswordStrength::Fair => "fair",
+            PasswordStrength::Good => "good",
+            PasswordStrength::Strong => "strong",
+        }
+    }
+}
+
+/// One point each for length (8 and 12+), mixed case, digits and symbols.
+/// Symbols include spaces, so long passphrases score well.
+/// Breach-list passwords and single repeated characters are always weak.
+pub fn password_strength(password: &str) -> PasswordStrength {
+    let len = password.chars().count();
+    let lowered = password.to_lowercase();
+    let first = password.chars().next();
+    if len < MIN_PASSWORD_LEN
+        || COMMON_PASSWORDS.contains(&lowered.as_str())
+        || password.chars().all(|c| Some(c) == first)
+    {
+        return PasswordStrength::Weak;
+    }
+
+    let has_lower = password.chars().any(char::is_lowercase);
+    let has_upper = password.chars().any(char::is_uppercase);
+    let score = [
+        true,
+        len >= 12,
+        has_lower && has_upper,
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|p| *p)
+    .count();
+
+    match score {
+        0..=1 => PasswordStrength::Weak,
+        2 => PasswordStrength::Fair,
+        3 => PasswordStrength::Good,
+        _ => PasswordStrength::Strong,
+    }
+}
+
+/// For choosing a new password (signup, reset). Signing in only needs it non-empty.
+pub fn validate_new_password(password: &str) -> Result<(), ValidationError> {
+    if password.is_empty() {
+        return Err(ValidationError::PasswordRequired);
+    }
+    if password.chars().count() < MIN_PASSWORD_LEN {
+        return Err(ValidationError::PasswordTooShort);
+    }
+    if password.len() > MAX_PASSWORD_BYTES {
+        return Err(ValidationError::PasswordTooLong);
+    }
+    if password_strength(password) == PasswordStrength::Weak {
+        return Err(ValidationError::PasswordTooWeak);
+    }
+    Ok(())
+}
+
+/// A 6-digit authenticator code. Apps often show it as "123 456", so spaces are dropped.
+pub fn validate_otp(input: &str) -> Result<String, ValidationError> {
+    let code: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if code.len() == 6 && code.chars().all(|c| c.is_ascii_digit()) {
+        Ok(code)
+    } else {
+        Err(ValidationError::OtpInvalid)
+    }
+}
+
+/// Recovery codes are compared by the server; we only tidy up case and spacing
+pub fn validate_recovery_code(input: &str) -> Result<String, ValidationError> {
+    let code: String = input.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_ascii_lowercase();
+    if code.is_empty() {
+        Err(ValidationError::RecoveryCodeRequired)
+    } else {
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_ordinary_emails() {
+        assert_eq!(validate_email("owner@station.ng"), Ok("owner@station.ng".into()));
+        assert_eq!(validate_email("  Ada.Obi+fuel@Mail.Example.COM "), Ok("Ada.Obi+fuel@mail.example.com".into()));
+        assert_eq!(validate_email("o'neil@x-y.co.uk"), Ok("o'neil@x-y.co.uk".into()));
+    }
+
+    #[test]
+    fn rejects_malformed_emails() {
+        assert_eq!(validate_email("   "), Err(ValidationError::EmailRequired));
+        for bad in [
+            "owner", "owner@", "@station.ng", "owner@station", "owner@@station.ng",
+            "owner@station..ng", ".owner@station.ng", "own..er@station.ng", "owner.@station.ng",
+            "own er@station.ng", "owner@-station.ng", "owner@station-.ng", "owner@station.n",
+            "owner@station.123", "owner@sta_tion.ng",
+        ] {
+            assert_eq!(validate_email(bad), Err(ValidationError::EmailInvalid), "{bad}");
+        }
+    }
+
+    #[test]
+    fn normalizes_the_usual_ways_of_writing_a_number() {
+        for input in [
+            "08031234567", "0803 123 4567", "0803-123-4567", "+2348031234567",
+            "+234 803 123 4567", "2348031234567", "+234 (0)803 123 4567", "8031234567",
+        ] {
+            assert_eq!(normalize_phone(input), Ok("+2348031234567".into()), "{input}");
+        }
+        assert_eq!(normalize_phone("0901 234 5678"), Ok("+2349012345678".into()));
+    }
+
+    #[test]
+    fn rejects_numbers_that_are_not_nigerian_mobiles() {
+        assert_eq!(normalize_phone(""), Err(ValidationError::PhoneRequired));
+        // 094x and 060x aren't allocated to mobile operators
+        assert_eq!(normalize_phone("09412345678"), Err(ValidationError::PhoneNotMobile));
+        assert_eq!(normalize_phone("06001234567"), Err(ValidationError::PhoneNotMobile));
+        for bad in ["0803123456", "080312345678", "+44 7700 900123", "0803abc4567", "+2348031234567890"] {
+            assert_eq!(normalize_phone(bad), Err(ValidationError::PhoneInvalid), "{bad}");
+        }
+    }
+
+    #[test]
+    fn rates_password_strength() {
+        assert_eq!(password_strength(""), PasswordStrength::Weak);
+        assert_eq!(password_strength("abc1"), PasswordStrength::Weak);
+        assert_eq!(password_strength("Password1"), PasswordStrength::Weak);
+        assert_eq!(password_strength("aaaaaaaaaaaaaa"), PasswordStrength::Weak);
+        assert_eq!(password_strength("fuelprice"), PasswordStrength::Weak);
+        assert_eq!(password_strength("fuelprice9"), PasswordStrength::Fair);
+        assert_eq!(password_strength("FuelPrice9"), PasswordStrength::Good);
+        assert_eq!(password_strength("FuelPrice9!"), PasswordStrength::Strong);
+        assert_eq!(password_strength("fuel price is 650 today"), PasswordStrength::Strong);
+    }
+
+    #[test]
+    fn new_passwords_must_be_long_and_not_weak() {
+        assert_eq!(validate_new_password(""), Err(ValidationError::PasswordRequired));
+        assert_eq!(validate_new_password("Ab1!"), Err(ValidationError::PasswordTooShort));
+        assert_eq!(validate_new_password("password123"), Err(ValidationError::PasswordTooWeak));
+        assert_eq!(validate_new_password(&"Ab1!".repeat(20)), Err(ValidationError::PasswordTooLong));
+        assert_eq!(validate_new_password("fuelprice9"), Ok(()));
+    }
+
+    #[test]
+    fn otp_codes_are_six_digits() {
+        assert_eq!(validate_otp("123456"), Ok("123456".into()));
+        assert_eq!(validate_otp(" 123 456 "), Ok("123456".into()));
+        for bad in ["", "12345", "1234567", "12a456", "123-456"] {
+            assert_eq!(validate_otp(bad), Err(ValidationError::OtpInvalid), "{bad}");
+        }
+    }
+
+    #[test]
+    fn recovery_codes_are_tidied() {
+        assert_eq!(validate_recovery_code(" AB12-CD34 "), Ok("ab12-cd34".into()));
+        assert_eq!(validate_recovery_code("   "), Err(ValidationError::RecoveryCodeRequired));
+    }
+}
@@ -0,0 +1,76 @@
+use std::f64::consts::PI;
+
+/// Slippy-map tiles are 256px squares at every zoom level
+pub const TILE_SIZE: f64 = 256.0;
+
+// Equatorial circumference / 256, i.e. metres per pixel at zoom 0 on the equator
+const METRES_PER_PIXEL_Z0: f64 = 156_543.033_92;
+
+fn world_size(zoom: u8) -> f64 {
+    TILE_SIZE * f64::from(1u32 << zoom)
+}
+
+/// `(lat, lon)` to pixel coordinates on the whole-world map at `zoom`
+pub fn to_world_px(lat: f64, lon: f64, zoom: u8) -> (f64, f64) {
+    let size = world_size(zoom);
+    let lat_rad = lat.to_radians();
+    let x = (lon + 180.0) / 360.0 * size;
+    let y = (1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI) / 2.0 * size;
+    (x, y)
+}
+
+/// Inverse of [`to_world_px`]
+pub fn from_world_px(x: f64, y: f64, zoom: u8) -> (f64, f64) {
+    let size = world_size(zoom);
+    let lon = x / size * 360.0 - 180.0;
+    let lat = (PI * (1.0 - 2.0 * y / size)).sinh().atan().to_degrees();
+    (lat, lon)
+}
+
+/// Ground distance one pixel covers at this latitude and zoom
+pub fn metres_per_pixel(lat: f64, zoom: u8) -> f64 {
+    METRES_PER_PIXEL_Z0 * lat.to_radians().cos() / f64::from(1u32 << zoom)
+}
+
+/// Fills a `{z}/{x}/{y}` tile URL template
+pub fn tile_url(template: &str, zoom: u8, x: i64, y: i64) -> String {
+    template
+        .replace("{z}", &zoom.to_string())
+        .replace("{x}", &x.to_string())
+        .replace("{y}", &y.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_pixels() {
+        // Central Area, Abuja
+        let (lat, lon) = (9.0579, 7.4951);
+        let (x, y) = to_world_px(lat, lon, 18);
+        let (lat2, lon2) = from_world_px(x, y, 18);
+        assert!((lat - lat2).abs() < 1e-9 && (lon - lon2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lands_on_the_expected_tile() {
+        let (x, y) = to_world_px(9.0579, 7.4951, 15);
+        assert_eq!(((x / TILE_SIZE) as i64, (y / TILE_SIZE) as i64), (17066, 15556));
+    }
+
+    #[test]
+    fn pixels_shrink_with_zoom_and_latitude() {
+        assert!((metres_per_pixel(0.0, 0) - 156_543.03).abs() < 0.01);
+        assert!((metres_per_pixel(9.0, 18) - 0.5897).abs() < 0.001);
+        assert!(metres_per_pixel(60.0, 18) < metres_per_pixel(9.0, 18));
+    }
+
+    #[test]
+    fn fills_tile_templates() {
+        assert_eq!(
+            tile_url("https://tile.example.com/{z}/{x}/{y}.png", 18, 136530, 124461),
+            "https://tile.example.com/18/136530/124461.png"
+        );
+    }
+}